@@ -0,0 +1,296 @@
+//! `#[derive(FromLine)]`: implements `FromStr` for types whose input lines follow a pattern.
+//!
+//! ```ignore
+//! #[derive(FromLine)]
+//! #[pattern("{x},{y}")]
+//! struct Point {
+//!     x: i64,
+//!     y: i64,
+//! }
+//!
+//! #[derive(FromLine)]
+//! enum Move {
+//!     #[pattern("forward {}")]
+//!     Forward(usize),
+//!     #[pattern("up {}")]
+//!     Up(usize),
+//! }
+//! ```
+//!
+//! Placeholders are named after the fields of a struct, or left empty to fill the fields of a
+//! tuple struct in order. `{{` and `}}` stand for literal braces. Each field is read with its own
+//! `FromStr` implementation, up to the text following its placeholder.
+//!
+//! The generated code fails with `advent_solver::diagnostic::Located<String>` and locates field
+//! errors through `advent_solver::diagnostic::FieldError`, so that the deriving crate must depend
+//! on advent-solver. Fields may fail with any error that displays.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, LitStr};
+
+#[derive(Debug, PartialEq)]
+enum Segment {
+    Literal(String),
+    /// A placeholder, named or positional
+    Field(Option<String>),
+}
+
+/// Splits a pattern into literal text and placeholders.
+fn segments(pattern: &str) -> Result<Vec<Segment>, String> {
+    let mut segments = vec![];
+    let mut literal = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(format!("Unclosed placeholder '{{{}'", name)),
+                    }
+                }
+                if matches!(segments.last(), Some(Segment::Field(_))) && literal.is_empty() {
+                    return Err("Placeholders must be separated by some text".into());
+                }
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+                segments.push(Segment::Field(if name.is_empty() { None } else { Some(name) }));
+            }
+            '}' => return Err("Unmatched '}', write '}}' for a literal one".into()),
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+    Ok(segments)
+}
+
+fn pattern(attrs: &[Attribute], span: &impl quote::ToTokens) -> Result<(LitStr, Vec<Segment>), Error> {
+    let attr = attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("pattern"))
+        .ok_or_else(|| Error::new_spanned(span, "Expected a #[pattern(\"...\")] attribute"))?;
+    let pattern: LitStr = attr.parse_args()?;
+    let segments = segments(&pattern.value()).map_err(|message| Error::new_spanned(&pattern, message))?;
+    Ok((pattern, segments))
+}
+
+/// An expression reading `line` as `pattern` into `constructor`, evaluating to
+/// `Result<Self, Located<String>>`.
+fn reader(
+    constructor: TokenStream2,
+    fields: &Fields,
+    pattern: &LitStr,
+    segments: &[Segment],
+) -> Result<TokenStream2, Error> {
+    let error = |message: &str| Error::new_spanned(pattern, message);
+    let placeholders: Vec<_> = segments
+        .iter()
+        .filter_map(|segment| match segment {
+            Segment::Field(name) => Some(name),
+            Segment::Literal(_) => None,
+        })
+        .collect();
+
+    // Index of the field each placeholder fills
+    let indices = match fields {
+        Fields::Named(named) => {
+            let names: Vec<String> = named
+                .named
+                .iter()
+                .map(|f| f.ident.as_ref().unwrap().to_string())
+                .collect();
+            let indices = placeholders
+                .iter()
+                .map(|name| {
+                    let name = name
+                        .as_ref()
+                        .ok_or_else(|| error("Placeholders of a struct with named fields need a name"))?;
+                    names
+                        .iter()
+                        .position(|field| field == name)
+                        .ok_or_else(|| error(&format!("No field named '{}'", name)))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            for (i, name) in names.iter().enumerate() {
+                if indices.iter().filter(|index| **index == i).count() != 1 {
+                    return Err(error(&format!("Field '{}' must appear exactly once", name)));
+                }
+            }
+            indices
+        }
+        Fields::Unnamed(unnamed) => {
+            if placeholders.iter().any(|name| name.is_some()) {
+                return Err(error("Placeholders of a tuple struct are written '{}'"));
+            }
+            if placeholders.len() != unnamed.unnamed.len() {
+                return Err(error(&format!("Expected {} placeholders", unnamed.unnamed.len())));
+            }
+            (0..placeholders.len()).collect()
+        }
+        Fields::Unit if placeholders.is_empty() => vec![],
+        Fields::Unit => return Err(error("No field to fill")),
+    };
+    let types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let variable = |index: usize| format_ident!("field_{}", index);
+
+    let mut steps = vec![];
+    let mut placeholder = 0;
+    for (i, segment) in segments.iter().enumerate() {
+        match segment {
+            Segment::Literal(literal) => steps.push(quote! {
+                let rest = match rest.strip_prefix(#literal) {
+                    Some(rest) => rest,
+                    None => {
+                        let next = &rest[..rest.chars().next().map_or(0, char::len_utf8)];
+                        return Err(Located::new(line, next, format!("Expected '{}'", #literal)));
+                    }
+                };
+            }),
+            Segment::Field(_) => {
+                let index = indices[placeholder];
+                placeholder += 1;
+                let (variable, ty) = (variable(index), types[index]);
+                let token = match segments.get(i + 1) {
+                    Some(Segment::Literal(next)) => quote! {
+                        let (token, rest) = match rest.find(#next) {
+                            Some(end) => rest.split_at(end),
+                            None => {
+                                let end = &rest[rest.len()..];
+                                return Err(Located::new(line, end, format!("Expected '{}'", #next)));
+                            }
+                        };
+                    },
+                    _ => quote! {
+                        let (token, rest) = rest.split_at(rest.len());
+                    },
+                };
+                steps.push(quote! {
+                    #token
+                    let #variable = <#ty as ::std::str::FromStr>::from_str(token)
+                        .map_err(|e| ::advent_solver::diagnostic::FieldError::locate(e, line, token))?;
+                });
+            }
+        }
+    }
+
+    let variables = (0..types.len()).map(variable);
+    let value = match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|field| &field.ident);
+            quote! { #constructor { #(#names: #variables),* } }
+        }
+        Fields::Unnamed(_) => quote! { #constructor(#(#variables),*) },
+        Fields::Unit => constructor,
+    };
+    Ok(quote! {
+        (|| -> Result<Self, Located<String>> {
+            let rest: &str = line;
+            #(#steps)*
+            if !rest.is_empty() {
+                return Err(Located::new(line, rest, format!("Unexpected '{}'", rest)));
+            }
+            Ok(#value)
+        })()
+    })
+}
+
+fn from_line(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, segments) = pattern(&input.attrs, name)?;
+            reader(quote! { Self }, &data.fields, &pattern, &segments)?
+        }
+        Data::Enum(data) => {
+            let mut patterns = vec![];
+            let mut readers = vec![];
+            for variant in &data.variants {
+                let (pattern, segments) = pattern(&variant.attrs, &variant.ident)?;
+                let ident = &variant.ident;
+                readers.push(reader(quote! { Self::#ident }, &variant.fields, &pattern, &segments)?);
+                patterns.push(format!("'{}'", pattern.value()));
+            }
+            let expected = format!("Expected {}", patterns.join(" or "));
+            // The variant that read the furthest tells what went wrong, unless none read anything
+            quote! {
+                let mut furthest: Option<Located<String>> = None;
+                #(
+                    match #readers {
+                        Ok(value) => return Ok(value),
+                        Err(e) if furthest.as_ref().map_or(true, |f| e.column > f.column) => furthest = Some(e),
+                        Err(_) => (),
+                    }
+                )*
+                match furthest {
+                    Some(e) if e.column > 0 => Err(e),
+                    _ => Err(Located::new(line, line, #expected.to_string())),
+                }
+            }
+        }
+        Data::Union(_) => return Err(Error::new_spanned(name, "FromLine can't be derived for unions")),
+    };
+
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::std::str::FromStr for #name #type_generics #where_clause {
+            type Err = ::advent_solver::diagnostic::Located<String>;
+
+            fn from_str(line: &str) -> Result<Self, Self::Err> {
+                use ::advent_solver::diagnostic::Located;
+                #body
+            }
+        }
+    })
+}
+
+#[proc_macro_derive(FromLine, attributes(pattern))]
+pub fn derive_from_line(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_line(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_splits_patterns() {
+        assert_eq!(
+            segments("{x},{y}").unwrap(),
+            vec![
+                Segment::Field(Some("x".into())),
+                Segment::Literal(",".into()),
+                Segment::Field(Some("y".into()))
+            ]
+        );
+        assert_eq!(
+            segments("{{{}}}").unwrap(),
+            vec![
+                Segment::Literal("{".into()),
+                Segment::Field(None),
+                Segment::Literal("}".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn it_rejects_ambiguous_patterns() {
+        assert!(segments("{x}{y}").is_err());
+        assert!(segments("{x").is_err());
+        assert!(segments("x}").is_err());
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt::Display;
+
+use num_bigint::BigInt;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A puzzle answer, whatever its shape.
+///
+/// Answers are printed, compared and serialized through their textual form, so that
+/// `Integer(5)`, `BigInteger(5)` and `Text("5")` are the same answer.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(BigInt),
+    Text(String),
+    /// ASCII-art answer, one string per row
+    Grid(Vec<String>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::BigInteger(n) => write!(f, "{}", n),
+            Answer::Text(s) => f.write_str(s),
+            Answer::Grid(rows) => f.write_str(&rows.join("\n")),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        match i64::try_from(&n) {
+            Ok(n) => Answer::Integer(n),
+            Err(_) => Answer::BigInteger(n),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    match i64::try_from(n) {
+                        Ok(n) => Answer::Integer(n),
+                        Err(_) => Answer::BigInteger(BigInt::from(n)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i32, u32, i64, u64, i128, u128, isize, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.into())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(rows: Vec<String>) -> Self {
+        Answer::Grid(rows)
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Integer(n) => serializer.serialize_i64(*n),
+            answer => serializer.serialize_str(&answer.to_string()),
+        }
+    }
+}
+
+/// Reads an integer, or a string which is a grid when it spans several lines.
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Integer(i64),
+            Text(String),
+        }
+
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::Integer(n) => Answer::Integer(n),
+            Raw::Text(s) if s.trim_end().contains('\n') => {
+                Answer::Grid(s.trim_end().lines().map(String::from).collect())
+            }
+            Raw::Text(s) => Answer::Text(s),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn it_compares_answers_by_their_text() {
+        assert_eq!(Answer::from(5usize), Answer::Text("5".into()));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+        assert!(matches!(Answer::from(u64::MAX), Answer::BigInteger(_)));
+        assert_ne!(Answer::from(-5), Answer::from(5));
+    }
+
+    #[test]
+    fn it_reads_multi_line_strings_as_grids() {
+        let mut answers: BTreeMap<String, Answer> = toml::from_str("part1 = \"\"\"\n#..\n.#.\n\"\"\"").unwrap();
+        let answer = answers.remove("part1").unwrap();
+        assert!(matches!(&answer, Answer::Grid(rows) if rows.len() == 2));
+        assert_eq!(answer.to_string(), "#..\n.#.");
+    }
+}
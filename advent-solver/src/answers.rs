@@ -0,0 +1,184 @@
+use std::collections::BTreeMap;
+
+use derive_more::Display;
+use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::registry::Part;
+
+#[derive(Debug, Display)]
+pub enum AnswersError {
+    #[display(fmt = "Can't read answers file {}: {}", _0, _1)]
+    Unreadable(String, String),
+    #[display(fmt = "Malformed answers file {}: {}", _0, _1)]
+    Malformed(String, String),
+    #[display(fmt = "Can't write answers file {}: {}", _0, _1)]
+    Unwritable(String, String),
+}
+
+/// Why the puzzle server turned an answer down.
+#[derive(Debug, Clone, Copy, PartialEq, Display, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Rejection {
+    #[display(fmt = "wrong")]
+    Wrong,
+    #[display(fmt = "too high")]
+    TooHigh,
+    #[display(fmt = "too low")]
+    TooLow,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Rejected {
+    pub answer: Answer,
+    pub verdict: Rejection,
+}
+
+/// Expected answers of one day, as stored in `answers/<year>/<day>.toml`:
+///
+/// ```toml
+/// [input]
+/// part1 = 1374
+/// part2 = 1418
+///
+/// [example]
+/// part1 = 7
+/// part2 = 5
+///
+/// [[rejected.part2]]
+/// answer = 1500
+/// verdict = "too-high"
+/// ```
+///
+/// Answers submitted to the puzzle server end up in `input` once accepted, or in `rejected`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    input: BTreeMap<String, Answer>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    example: BTreeMap<String, Answer>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    rejected: BTreeMap<String, Vec<Rejected>>,
+}
+
+fn key(part: Part) -> String {
+    format!("part{}", part)
+}
+
+fn integer(answer: &Answer) -> Option<BigInt> {
+    answer.to_string().parse().ok()
+}
+
+pub fn file_name(year: u16, day: usize) -> String {
+    format!("answers/{}/{}.toml", year, day)
+}
+
+impl Answers {
+    /// Loads the answers of `day` of `year`, a missing file meaning nothing is expected yet.
+    pub fn load(year: u16, day: usize) -> Result<Self, AnswersError> {
+        let file_name = file_name(year, day);
+        match std::fs::read_to_string(&file_name) {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(AnswersError::Unreadable(file_name, e.to_string())),
+            Ok(content) => Self::from_toml(&content).map_err(|e| AnswersError::Malformed(file_name, e)),
+        }
+    }
+
+    fn from_toml(content: &str) -> Result<Self, String> {
+        toml::from_str(content).map_err(|e| e.to_string())
+    }
+
+    pub fn expected(&self, part: Part, example: bool) -> Option<&Answer> {
+        let answers = match example {
+            false => &self.input,
+            true => &self.example,
+        };
+        answers.get(&key(part))
+    }
+
+    /// How the server already turned `answer` down, were it submitted again. Beyond answers
+    /// submitted as is, integers past one known to be too high or too low are rejected alike.
+    pub fn rejection(&self, part: Part, answer: &Answer) -> Option<Rejection> {
+        let rejected = self.rejected.get(&key(part))?;
+        if let Some(known) = rejected.iter().find(|rejected| &rejected.answer == answer) {
+            return Some(known.verdict);
+        }
+        let n = integer(answer)?;
+        rejected.iter().find_map(|rejected| {
+            let bound = integer(&rejected.answer)?;
+            match rejected.verdict {
+                Rejection::TooHigh if n >= bound => Some(Rejection::TooHigh),
+                Rejection::TooLow if n <= bound => Some(Rejection::TooLow),
+                _ => None,
+            }
+        })
+    }
+
+    pub fn accept(&mut self, part: Part, answer: Answer) {
+        self.input.insert(key(part), answer);
+    }
+
+    pub fn reject(&mut self, part: Part, answer: Answer, verdict: Rejection) {
+        self.rejected
+            .entry(key(part))
+            .or_default()
+            .push(Rejected { answer, verdict });
+    }
+
+    /// Writes the answers of `day` of `year` back, comments of the previous file aside.
+    pub fn save(&self, year: u16, day: usize) -> Result<(), AnswersError> {
+        let file_name = file_name(year, day);
+        let unwritable = |e: String| AnswersError::Unwritable(file_name.clone(), e);
+        let content = toml::to_string(self).map_err(|e| unwritable(e.to_string()))?;
+        std::fs::create_dir_all(format!("answers/{}", year)).map_err(|e| unwritable(e.to_string()))?;
+        std::fs::write(&file_name, content).map_err(|e| unwritable(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_reads_numbers_and_strings() {
+        let answers = Answers::from_toml(
+            r#"
+            [input]
+            part1 = 1374
+            part2 = "EFJKZLBL"
+
+            [example]
+            part1 = 7
+            "#,
+        )
+        .unwrap();
+        assert_eq!(answers.expected(Part::One, false), Some(&Answer::Integer(1374)));
+        assert_eq!(
+            answers.expected(Part::Two, false),
+            Some(&Answer::Text("EFJKZLBL".into()))
+        );
+        assert_eq!(answers.expected(Part::One, true).unwrap().to_string(), "7");
+        assert_eq!(answers.expected(Part::Two, true), None);
+    }
+
+    #[test]
+    fn it_remembers_rejected_answers() {
+        let mut answers = Answers::default();
+        answers.reject(Part::One, 1500.into(), Rejection::TooHigh);
+        answers.reject(Part::One, 900.into(), Rejection::TooLow);
+        answers.reject(Part::One, "1200".into(), Rejection::Wrong);
+        let answers = Answers::from_toml(&toml::to_string(&answers).unwrap()).unwrap();
+
+        assert_eq!(answers.rejection(Part::One, &1200.into()), Some(Rejection::Wrong));
+        assert_eq!(answers.rejection(Part::One, &1501.into()), Some(Rejection::TooHigh));
+        assert_eq!(answers.rejection(Part::One, &900.into()), Some(Rejection::TooLow));
+        assert_eq!(answers.rejection(Part::One, &1000.into()), None);
+        assert_eq!(answers.rejection(Part::Two, &1200.into()), None);
+    }
+
+    #[test]
+    fn it_rejects_malformed_answers() {
+        assert!(Answers::from_toml("[input]\npart1 = [1, 2]").is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::{BitAnd, BitOr, BitXor, Deref};
+use std::ops::{BitAnd, BitOr, BitXor};
 
 #[derive(Hash, Copy, Clone, PartialEq, Eq, Debug)]
 pub struct BinSeq(pub [bool; 7]);
@@ -7,7 +7,7 @@ impl BitXor for BinSeq {
     type Output = Self;
 
     fn bitxor(self, rhs: Self) -> Self::Output {
-        BinSeq(self.0.zip(rhs.0).map(|(l, r)| l.bitxor(r)))
+        BinSeq(self.zip(&rhs).map(|(l, r)| l.bitxor(r)))
     }
 }
 
@@ -15,7 +15,7 @@ impl BitAnd for BinSeq {
     type Output = Self;
 
     fn bitand(self, rhs: Self) -> Self::Output {
-        BinSeq(self.0.zip(rhs.0).map(|(l, r)| l.bitand(r)))
+        BinSeq(self.zip(&rhs).map(|(l, r)| l.bitand(r)))
     }
 }
 
@@ -23,15 +23,15 @@ impl BitOr for BinSeq {
     type Output = Self;
 
     fn bitor(self, rhs: Self) -> Self::Output {
-        BinSeq(self.0.zip(rhs.0).map(|(l, r)| l.bitor(r)))
+        BinSeq(self.zip(&rhs).map(|(l, r)| l.bitor(r)))
     }
 }
 
 impl PartialOrd for BinSeq {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         let implies = |(a, b): &(bool, bool)| *b || !*a;
-        let included_in_other = self.0.zip(other.0).iter().all(implies);
-        let including_oter = other.0.zip(other.0).iter().all(implies);
+        let included_in_other = self.zip(other).iter().all(implies);
+        let including_oter = other.zip(other).iter().all(implies);
         match (included_in_other, including_oter) {
             (false, false) => None,
             (true, false) => Some(std::cmp::Ordering::Less),
@@ -42,6 +42,11 @@ impl PartialOrd for BinSeq {
 }
 
 impl BinSeq {
+    /// Pairs the bits of both sequences, position by position.
+    fn zip(&self, other: &BinSeq) -> [(bool, bool); 7] {
+        std::array::from_fn(|i| (self.0[i], other.0[i]))
+    }
+
     pub fn card(&self) -> usize {
         self.0.into_iter().filter(|b| *b).count()
     }
@@ -0,0 +1,321 @@
+//! Small parser combinators reading puzzle inputs line by line.
+//!
+//! A parser takes what is left of a line and returns the value it read along with the rest of
+//! the line, or a [`Failure`] pointing at the offending slice of the line. Failures thus end up
+//! as [`Diagnostic`]s carrying the line and column of the error.
+use std::fmt::Display;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::diagnostic::Diagnostic;
+use crate::solver;
+
+/// Why a parser failed, `at` being a slice of the parsed line.
+#[derive(Debug, PartialEq)]
+pub struct Failure<'a> {
+    pub at: &'a str,
+    pub message: String,
+}
+
+impl<'a> Failure<'a> {
+    pub fn new(at: &'a str, message: impl Display) -> Self {
+        Self {
+            at,
+            message: message.to_string(),
+        }
+    }
+}
+
+pub type ParseResult<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+/// The next character of `input`, or the empty slice at its end.
+fn next_char(input: &str) -> &str {
+    &input[..input.chars().next().map_or(0, char::len_utf8)]
+}
+
+/// What `input` lost to become `rest`.
+fn consumed<'a>(input: &'a str, rest: &str) -> &'a str {
+    &input[..input.len() - rest.len()]
+}
+
+/// An integer, optionally negative.
+pub fn number<T>() -> impl Fn(&str) -> ParseResult<'_, T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    |input: &str| {
+        let sign = usize::from(input.starts_with('-'));
+        let end = input[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(input.len(), |end| end + sign);
+        if end == sign {
+            return Err(Failure::new(next_char(input), "Expected a number"));
+        }
+        let (token, rest) = input.split_at(end);
+        T::from_str(token)
+            .map(|n| (n, rest))
+            .map_err(|e| Failure::new(token, format!("Not a number: {}", e)))
+    }
+}
+
+/// Exactly `expected`.
+pub fn literal(expected: &'static str) -> impl Fn(&str) -> ParseResult<'_, ()> {
+    move |input: &str| match input.strip_prefix(expected) {
+        Some(rest) => Ok(((), rest)),
+        None => {
+            let matching = input
+                .char_indices()
+                .zip(expected.chars())
+                .find(|((_, found), wanted)| found != wanted)
+                .map_or(input.len(), |((i, _), _)| i);
+            Err(Failure::new(
+                next_char(&input[matching..]),
+                format!("Expected '{}'", expected),
+            ))
+        }
+    }
+}
+
+/// A run of letters and digits.
+pub fn word() -> impl Fn(&str) -> ParseResult<'_, String> {
+    |input: &str| {
+        let end = input.find(|c: char| !c.is_alphanumeric()).unwrap_or(input.len());
+        match input.split_at(end) {
+            ("", _) => Err(Failure::new(next_char(input), "Expected a word")),
+            (token, rest) => Ok((token.into(), rest)),
+        }
+    }
+}
+
+/// Any number of spaces, none included.
+pub fn spaces() -> impl Fn(&str) -> ParseResult<'_, ()> {
+    |input: &str| Ok(((), input.trim_start_matches(' ')))
+}
+
+/// At least one space.
+pub fn space() -> impl Fn(&str) -> ParseResult<'_, ()> {
+    |input: &str| match input.strip_prefix(' ') {
+        Some(rest) => Ok(((), rest.trim_start_matches(' '))),
+        None => Err(Failure::new(next_char(input), "Expected a space")),
+    }
+}
+
+pub fn map<T, U>(
+    parser: impl Fn(&str) -> ParseResult<'_, T>,
+    f: impl Fn(T) -> U,
+) -> impl Fn(&str) -> ParseResult<'_, U> {
+    move |input: &str| parser(input).map(|(value, rest)| (f(value), rest))
+}
+
+/// Converts what `parser` read with `f`, locating the errors of `f` on what `parser` consumed.
+pub fn map_res<T, U, E: Display>(
+    parser: impl Fn(&str) -> ParseResult<'_, T>,
+    f: impl Fn(T) -> Result<U, E>,
+) -> impl Fn(&str) -> ParseResult<'_, U> {
+    move |input: &str| {
+        let (value, rest) = parser(input)?;
+        f(value)
+            .map(|value| (value, rest))
+            .map_err(|e| Failure::new(consumed(input, rest), e))
+    }
+}
+
+pub fn pair<T, U>(
+    first: impl Fn(&str) -> ParseResult<'_, T>,
+    second: impl Fn(&str) -> ParseResult<'_, U>,
+) -> impl Fn(&str) -> ParseResult<'_, (T, U)> {
+    move |input: &str| {
+        let (a, rest) = first(input)?;
+        let (b, rest) = second(rest)?;
+        Ok(((a, b), rest))
+    }
+}
+
+/// `parser`, once `prefix` is read.
+pub fn preceded<T, U>(
+    prefix: impl Fn(&str) -> ParseResult<'_, T>,
+    parser: impl Fn(&str) -> ParseResult<'_, U>,
+) -> impl Fn(&str) -> ParseResult<'_, U> {
+    map(pair(prefix, parser), |(_, value)| value)
+}
+
+/// `parser`, followed by `suffix`.
+pub fn terminated<T, U>(
+    parser: impl Fn(&str) -> ParseResult<'_, T>,
+    suffix: impl Fn(&str) -> ParseResult<'_, U>,
+) -> impl Fn(&str) -> ParseResult<'_, T> {
+    map(pair(parser, suffix), |(value, _)| value)
+}
+
+/// Up to `max` items separated by `separator`. Once a separator is read, an item must follow.
+fn repeat<'a, T, U>(
+    input: &'a str,
+    item: &impl Fn(&str) -> ParseResult<'_, T>,
+    separator: &impl Fn(&str) -> ParseResult<'_, U>,
+    max: usize,
+) -> ParseResult<'a, Vec<T>> {
+    let (first, mut rest) = item(input)?;
+    let mut items = vec![first];
+    while items.len() < max {
+        match separator(rest) {
+            Ok((_, after)) => {
+                let (next, after) = item(after)?;
+                items.push(next);
+                rest = after;
+            }
+            Err(_) => break,
+        }
+    }
+    Ok((items, rest))
+}
+
+/// One or more items separated by `separator`.
+pub fn separated<T, U>(
+    item: impl Fn(&str) -> ParseResult<'_, T>,
+    separator: impl Fn(&str) -> ParseResult<'_, U>,
+) -> impl Fn(&str) -> ParseResult<'_, Vec<T>> {
+    move |input: &str| repeat(input, &item, &separator, usize::MAX)
+}
+
+/// Exactly `count` items separated by `separator`, for rows whose width is only known at runtime.
+pub fn exactly<T, U>(
+    count: usize,
+    item: impl Fn(&str) -> ParseResult<'_, T>,
+    separator: impl Fn(&str) -> ParseResult<'_, U>,
+) -> impl Fn(&str) -> ParseResult<'_, Vec<T>> {
+    move |input: &str| {
+        let (items, rest) = repeat(input, &item, &separator, count)?;
+        if items.len() < count {
+            return Err(Failure::new(
+                consumed(input, rest),
+                format!("Expected {} values, found {}", count, items.len()),
+            ));
+        }
+        Ok((items, rest))
+    }
+}
+
+/// Exactly `N` items separated by `separator`.
+pub fn array<const N: usize, T, U>(
+    item: impl Fn(&str) -> ParseResult<'_, T>,
+    separator: impl Fn(&str) -> ParseResult<'_, U>,
+) -> impl Fn(&str) -> ParseResult<'_, [T; N]> {
+    move |input: &str| {
+        let (items, rest) = repeat(input, &item, &separator, N)?;
+        let items: [T; N] = items.try_into().map_err(|items: Vec<T>| {
+            Failure::new(
+                consumed(input, rest),
+                format!("Expected {} values, found {}", N, items.len()),
+            )
+        })?;
+        Ok((items, rest))
+    }
+}
+
+/// The rest of the line, read one character at a time by `cell`. `expected` describes the
+/// characters `cell` accepts.
+pub fn row<T>(cell: impl Fn(char) -> Option<T>, expected: &'static str) -> impl Fn(&str) -> ParseResult<'_, Vec<T>> {
+    move |input: &str| {
+        input
+            .char_indices()
+            .map(|(i, c)| {
+                cell(c).ok_or_else(|| {
+                    let message = format!("Unexpected character, expected {}", expected);
+                    Failure::new(next_char(&input[i..]), message)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|cells| (cells, &input[input.len()..]))
+    }
+}
+
+/// Reads every line of `input` as a [`row`], all rows being as wide as the first one.
+pub fn grid<T>(
+    input: &mut dyn BufRead,
+    cell: impl Fn(char) -> Option<T>,
+    expected: &'static str,
+) -> Result<Vec<Vec<T>>, solver::Error> {
+    let row = row(cell, expected);
+    let mut rows: Vec<Vec<T>> = vec![];
+    for (line_index, line) in input.lines().enumerate() {
+        let line = line?;
+        let cells = diagnose(line_index, &line, &row).map_err(solver::Error::WrongLine)?;
+        if let Some(width) = rows.first().map(Vec::len).filter(|width| *width != cells.len()) {
+            let message = format!("Expected {} cells like the first row, found {}", width, cells.len());
+            return Err(solver::Error::WrongLine(Diagnostic::new(line_index, &line, message)));
+        }
+        rows.push(cells);
+    }
+    Ok(rows)
+}
+
+/// Reads the whole `line` with `parser`, failing on whatever it leaves unread.
+pub fn parse_line<T>(line: &str, parser: impl Fn(&str) -> ParseResult<'_, T>) -> Result<T, Failure<'_>> {
+    match parser(line)? {
+        (value, "") => Ok(value),
+        (_, rest) => Err(Failure::new(rest, format!("Unexpected '{}'", rest))),
+    }
+}
+
+/// Same as [`parse_line`], diagnosing failures as found on the line at `line_index`.
+pub fn diagnose<T>(
+    line_index: usize,
+    line: &str,
+    parser: impl Fn(&str) -> ParseResult<'_, T>,
+) -> Result<T, Diagnostic> {
+    parse_line(line, parser).map_err(|failure| Diagnostic::at(line_index, line, failure.at, failure.message))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_reads_separated_numbers() {
+        assert_eq!(
+            parse_line("3,-4,5", separated(number::<i64>(), literal(","))).unwrap(),
+            vec![3, -4, 5]
+        );
+        let line = "3,4,x";
+        let failure = parse_line(line, separated(number::<i64>(), literal(","))).unwrap_err();
+        assert_eq!(failure, Failure::new(&line[4..], "Expected a number"));
+    }
+
+    #[test]
+    fn it_locates_failures_in_the_line() {
+        let line = "0,9 -> 5,9";
+        let point = || array::<2, _, _>(number::<i64>(), literal(","));
+        let diagnostic = diagnose(2, line, pair(point(), preceded(literal(" => "), point()))).unwrap_err();
+        assert_eq!(diagnostic.to_string(), "3:5: Expected ' => '");
+        assert_eq!((diagnostic.column, diagnostic.width), (4, 1));
+
+        let failure = diagnose(0, "1 2 3", array::<4, _, _>(number::<u8>(), space())).unwrap_err();
+        assert_eq!((failure.column, failure.width), (0, 5));
+        assert_eq!(failure.message, "Expected 4 values, found 3");
+
+        let failure = diagnose(0, "1 2 3", array::<2, _, _>(number::<u8>(), space())).unwrap_err();
+        assert_eq!((failure.column, failure.message.as_str()), (3, "Unexpected ' 3'"));
+    }
+
+    #[test]
+    fn it_locates_mapping_errors_on_what_was_read() {
+        let even = || map_res(number::<u8>(), |n| (n % 2 == 0).then_some(n).ok_or("Odd"));
+        let line = "4 37";
+        let failure = parse_line(line, pair(terminated(even(), space()), even())).unwrap_err();
+        assert_eq!(failure, Failure::new(&line[2..], "Odd"));
+    }
+
+    #[test]
+    fn it_reads_grids_of_equal_rows() {
+        let bit = |c| match c {
+            '0' => Some(false),
+            '1' => Some(true),
+            _ => None,
+        };
+        let grid_of = |text: &str| grid(&mut text.as_bytes(), bit, "'0' or '1'");
+        assert_eq!(grid_of("01\n10").unwrap(), vec![vec![false, true], vec![true, false]]);
+        assert!(matches!(grid_of("01\n1"), Err(solver::Error::WrongLine(d)) if d.line_number == 2));
+        assert!(matches!(grid_of("01\n1x"), Err(solver::Error::WrongLine(d)) if d.column == 1));
+    }
+}
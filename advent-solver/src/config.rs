@@ -0,0 +1,73 @@
+use derive_more::Display;
+use serde::Deserialize;
+
+pub const FILE_NAME: &str = "advent.toml";
+
+#[derive(Debug, Display)]
+pub enum ConfigError {
+    #[display(fmt = "Can't read config file {}: {}", _0, _1)]
+    Unreadable(String, String),
+    #[display(fmt = "Malformed config file {}: {}", _0, _1)]
+    Malformed(String, String),
+}
+
+/// How to reach the puzzle server, as stored in `advent.toml`:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// rate_limit = 5
+/// ```
+///
+/// `ADVENT_SESSION` and `ADVENT_BASE_URL` take precedence over the file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged in browser
+    pub session: Option<String>,
+    pub base_url: String,
+    /// Seconds to wait between two requests to the server
+    pub rate_limit: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: "https://adventofcode.com".into(),
+            rate_limit: 5,
+        }
+    }
+}
+
+impl Config {
+    /// Loads `advent.toml` from the working directory, a missing file meaning defaults.
+    pub fn load() -> Result<Self, ConfigError> {
+        let mut config = match std::fs::read_to_string(FILE_NAME) {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Config::default(),
+            Err(e) => return Err(ConfigError::Unreadable(FILE_NAME.into(), e.to_string())),
+            Ok(content) => {
+                toml::from_str(&content).map_err(|e| ConfigError::Malformed(FILE_NAME.into(), e.to_string()))?
+            }
+        };
+        if let Ok(session) = std::env::var("ADVENT_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var("ADVENT_BASE_URL") {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_defaults_missing_keys() {
+        let config: Config = toml::from_str("session = \"abc\"").unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.base_url, "https://adventofcode.com");
+    }
+}
@@ -0,0 +1,182 @@
+use std::any::Any;
+use std::fmt::Display;
+use std::num::{ParseFloatError, ParseIntError};
+
+/// A parsing failure located in the input: which line, which token in it, and why.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    /// 1-based number of the offending line
+    pub line_number: usize,
+    /// Byte offset of the offending token in the line
+    pub column: usize,
+    /// Byte length of the offending token
+    pub width: usize,
+    pub line: String,
+    pub message: String,
+}
+
+/// Byte offset of `token` in `text`, if `token` is a slice of `text`.
+pub fn offset(text: &str, token: &str) -> Option<usize> {
+    let start = text.as_ptr() as usize;
+    let token_start = token.as_ptr() as usize;
+    (token_start >= start && token_start + token.len() <= start + text.len()).then(|| token_start - start)
+}
+
+impl Diagnostic {
+    /// Diagnoses the whole line at `line_index` (0-based, as given by `enumerate`).
+    pub fn new(line_index: usize, line: &str, message: impl Display) -> Self {
+        Self {
+            line_number: line_index + 1,
+            column: 0,
+            width: line.len(),
+            line: line.into(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Diagnoses `token`, a slice of the line at `line_index`.
+    pub fn at(line_index: usize, line: &str, token: &str, message: impl Display) -> Self {
+        Located::new(line, token, message).into_diagnostic(line_index, line)
+    }
+
+    pub fn at_column(mut self, column: usize, width: usize) -> Self {
+        self.column = column.min(self.line.len());
+        self.width = width.min(self.line.len() - self.column);
+        self
+    }
+
+    /// Renders the diagnostic the way rustc does, `source` naming the input it comes from.
+    pub fn render(&self, source: &str) -> String {
+        let number = self.line_number.to_string();
+        let gutter = " ".repeat(number.len());
+        let padding: String = self.line[..self.column]
+            .chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let carets = "^".repeat(self.line[self.column..self.column + self.width].chars().count().max(1));
+        format!(
+            "error: {message}\n{gutter}--> {source}:{line}:{column}\n{gutter} |\n{number} | {text}\n{gutter} | {padding}{carets}",
+            message = self.message,
+            gutter = gutter,
+            source = source,
+            line = self.line_number,
+            column = self.column + 1,
+            number = number,
+            text = self.line,
+            padding = padding,
+            carets = carets,
+        )
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line_number, self.column + 1, self.message)
+    }
+}
+
+/// An error about a token of the parsed text, located by its byte offset in that text.
+#[derive(Debug)]
+pub struct Located<E> {
+    pub column: usize,
+    pub width: usize,
+    pub error: E,
+}
+
+impl<E> Located<E> {
+    /// Locates `error` at `token`, a slice of `text`.
+    pub fn new(text: &str, token: &str, error: E) -> Self {
+        match offset(text, token) {
+            Some(column) => Self {
+                column,
+                width: token.len(),
+                error,
+            },
+            None => Self {
+                column: 0,
+                width: text.len(),
+                error,
+            },
+        }
+    }
+}
+
+impl<E: Display> Located<E> {
+    pub fn into_diagnostic(self, line_index: usize, line: &str) -> Diagnostic {
+        Diagnostic::new(line_index, line, self.error).at_column(self.column, self.width)
+    }
+}
+
+/// An error met while reading `token`, a field of a `#[derive(FromLine)]` line.
+pub trait FieldError {
+    fn locate(self, line: &str, token: &str) -> Located<String>;
+}
+
+/// Fields that are lines of their own keep the location of their error.
+impl FieldError for Located<String> {
+    fn locate(self, line: &str, token: &str) -> Located<String> {
+        Located {
+            column: self.column + offset(line, token).unwrap_or(0),
+            ..self
+        }
+    }
+}
+
+/// Any other field tells what went wrong, number fields that a number was expected.
+impl<E: Display + 'static> FieldError for E {
+    fn locate(self, line: &str, token: &str) -> Located<String> {
+        let error: &dyn Any = &self;
+        let message = match error.is::<ParseIntError>() || error.is::<ParseFloatError>() {
+            true => format!("Not a number: {}", self),
+            false => self.to_string(),
+        };
+        Located::new(line, token, message)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_renders_a_caret_under_the_token() {
+        let line = "0,9 -> x,9";
+        let diagnostic = Diagnostic::at(2, line, &line[7..8], "Not a digit: 'x'");
+        assert_eq!(
+            diagnostic.render("inputs/5"),
+            "error: Not a digit: 'x'\n --> inputs/5:3:8\n  |\n3 | 0,9 -> x,9\n  |        ^"
+        );
+        assert_eq!(diagnostic.to_string(), "3:8: Not a digit: 'x'");
+    }
+
+    #[test]
+    fn it_falls_back_on_whole_line_for_foreign_tokens() {
+        let diagnostic = Diagnostic::at(0, "abcdef", &String::from("cd"), "oops");
+        assert_eq!((diagnostic.column, diagnostic.width), (0, 6));
+    }
+
+    #[derive(Debug, advent_derive::FromLine)]
+    #[pattern("{name}: {weight} kg, {fragile}")]
+    struct Parcel {
+        name: char,
+        weight: f64,
+        fragile: bool,
+    }
+
+    #[test]
+    fn it_locates_errors_of_any_field() {
+        let parcel: Parcel = "a: 2.5 kg, true".parse().unwrap();
+        assert_eq!((parcel.name, parcel.weight, parcel.fragile), ('a', 2.5, true));
+
+        let error = "a: 2,5 kg, true".parse::<Parcel>().unwrap_err();
+        assert_eq!(
+            (error.column, error.error.as_str()),
+            (3, "Not a number: invalid float literal")
+        );
+        let error = "a: 2.5 kg, yes".parse::<Parcel>().unwrap_err();
+        assert_eq!(
+            (error.column, error.error.as_str()),
+            (11, "provided string was not `true` or `false`")
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::server::{unwritable, Server, ServerError};
+
+/// Where the input of a day comes from.
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Downloaded(String),
+    /// Already there, the server was left alone
+    Cached(String),
+}
+
+/// Downloads personal puzzle inputs into a directory, once per day.
+pub struct Fetcher {
+    server: Server,
+    inputs: PathBuf,
+}
+
+impl Fetcher {
+    /// A fetcher filling `inputs`, one directory per year.
+    pub fn new(server: Server, inputs: impl Into<PathBuf>) -> Self {
+        Self {
+            server,
+            inputs: inputs.into(),
+        }
+    }
+
+    pub fn fetch(&self, year: u16, day: usize) -> Result<Fetched, ServerError> {
+        let directory = self.inputs.join(year.to_string());
+        let file = directory.join(day.to_string());
+        if file.exists() {
+            return Ok(Fetched::Cached(file.display().to_string()));
+        }
+        let input = self.server.get(&format!("/{}/day/{}/input", year, day))?;
+
+        // Written aside then renamed, so that an interrupted download is never taken for a cached input
+        fs::create_dir_all(&directory).map_err(unwritable(&directory))?;
+        let partial = directory.join(format!(".{}.part", day));
+        fs::write(&partial, input).map_err(unwritable(&partial))?;
+        fs::rename(&partial, &file).map_err(unwritable(&file))?;
+        Ok(Fetched::Downloaded(file.display().to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::Config;
+    use crate::server::stand_in_server;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("advent-solver-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn fetcher(base_url: String, inputs: &PathBuf) -> Fetcher {
+        let config = Config {
+            session: Some("c0ffee".into()),
+            base_url,
+            rate_limit: 0,
+        };
+        Fetcher::new(Server::new(config, inputs), inputs)
+    }
+
+    #[test]
+    fn it_downloads_inputs_once() {
+        let (base_url, server) = stand_in_server(1, "200 OK", "199\n200\n");
+        let inputs = scratch_dir("fetch");
+        let fetcher = fetcher(base_url, &inputs);
+        let file = inputs.join("2021").join("1").display().to_string();
+
+        assert_eq!(fetcher.fetch(2021, 1).unwrap(), Fetched::Downloaded(file.clone()));
+        assert_eq!(fetcher.fetch(2021, 1).unwrap(), Fetched::Cached(file.clone()));
+        assert_eq!(fs::read_to_string(&file).unwrap(), "199\n200\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2021/day/1/input HTTP/1.1"));
+        assert!(requests[0].contains("Cookie: session=c0ffee"));
+        fs::remove_dir_all(inputs).unwrap();
+    }
+
+    #[test]
+    fn it_keeps_nothing_of_refused_requests() {
+        let (base_url, server) = stand_in_server(1, "400 Bad Request", "Puzzle inputs differ by user.\n");
+        let inputs = scratch_dir("refused");
+        let fetcher = fetcher(base_url, &inputs);
+
+        let error = fetcher.fetch(2021, 2).unwrap_err();
+        assert!(matches!(error, ServerError::Refused(_, 400, reason) if reason == "Puzzle inputs differ by user."));
+        assert!(!inputs.join("2021").join("2").exists());
+        server.join().unwrap();
+        fs::remove_dir_all(inputs).unwrap();
+    }
+}
@@ -0,0 +1,94 @@
+//! Puzzle solutions along with what it takes to run them: the solver traits, the registry of
+//! every year's days, the runner timing them, and the client of the puzzle server.
+//!
+//! ```
+//! use advent_solver::registry::{calendar, Part};
+//! use advent_solver::runner;
+//!
+//! let registry = calendar(2021).unwrap();
+//! let report = runner::run(registry.get(7, Part::One).unwrap().as_ref(), &mut "16,1,2,0,4,2,7,1,2,14".as_bytes());
+//! assert_eq!(report.outcome.unwrap(), 37.into());
+//! ```
+//!
+//! Each year is a module of its own, `year_2021::day_7::CrabCrew` being the model of that day.
+
+use std::io::{self, BufRead, Write};
+
+// `#[derive(FromLine)]` names the items it needs `::advent_solver::...`, days included
+extern crate self as advent_solver;
+
+pub mod answer;
+pub mod answers;
+pub mod application;
+pub mod bin_seq;
+pub mod combinator;
+pub mod config;
+pub mod diagnostic;
+pub mod fetch;
+pub mod output;
+pub mod param;
+pub mod pool;
+pub mod pop_array;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod schooler;
+pub mod server;
+pub mod solver;
+pub mod submit;
+
+pub mod year_2021;
+
+/// Solves every part of `day` of `year` from a single parse of `input`, writing the answers to
+/// `output` and the errors to stderr. This is all the stand-alone day binaries do, `params`
+/// being their arguments: `<name>=<value>` assignments of the puzzle constants.
+///
+/// Returns whether every part was solved.
+pub fn standalone(
+    year: u16,
+    day: usize,
+    params: &[String],
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+) -> io::Result<bool> {
+    let registry = registry::calendar(year);
+    let exercices = registry.as_ref().map(|registry| registry.day(day)).unwrap_or_default();
+    if exercices.is_empty() {
+        eprintln!("error: No solver registered for day {} of {}", day, year);
+        return Ok(false);
+    }
+    let declared: Vec<_> = exercices.iter().flat_map(|(_, exercice)| exercice.params()).collect();
+    let params = params
+        .iter()
+        .map(|assignment| assignment.parse())
+        .collect::<Result<Vec<_>, _>>()
+        .and_then(|assignments| param::Params::new(&assignments, &declared));
+    let params = match params {
+        Ok(params) => params,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return Ok(false);
+        }
+    };
+    let (parts, exercices): (Vec<_>, Vec<_>) = exercices
+        .into_iter()
+        .map(|(part, exercice)| (part, exercice.with_params(&params)))
+        .unzip();
+    let exercices: Vec<_> = exercices.iter().map(AsRef::as_ref).collect();
+    let parts = parts.into_iter().zip(runner::run_parts(&exercices, input));
+    let mut solved = true;
+    for (part, report) in parts {
+        match report.outcome {
+            Ok(answer) => writeln!(output, "Part {}: {}", part, answer)?,
+            Err(solver::Error::WrongLine(diagnostic)) => {
+                eprintln!("{}", diagnostic.render("<stdin>"));
+                return Ok(false);
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                solved = false;
+            }
+        }
+    }
+    Ok(solved)
+}
@@ -0,0 +1,55 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// Writes log records to stderr, keeping stdout for answers.
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let target = record.target().trim_start_matches("advent_solver::");
+            eprintln!("[{:<5} {}] {}", record.level(), target, record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Level shown for `verbose` occurrences of `-v` and `quiet` ones of `-q`, warnings by default.
+pub fn level(verbose: u8, quiet: u8) -> LevelFilter {
+    let levels = [
+        LevelFilter::Off,
+        LevelFilter::Error,
+        LevelFilter::Warn,
+        LevelFilter::Info,
+        LevelFilter::Debug,
+        LevelFilter::Trace,
+    ];
+    let default = Level::Warn as usize;
+    let index = (default + verbose as usize).saturating_sub(quiet as usize);
+    levels[index.min(levels.len() - 1)]
+}
+
+pub fn init(level: LevelFilter) {
+    log::set_max_level(level);
+    // Only fails when a logger is already set, which is then kept
+    let _ = log::set_logger(&StderrLogger);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_maps_flags_to_levels() {
+        assert_eq!(level(0, 0), LevelFilter::Warn);
+        assert_eq!(level(2, 0), LevelFilter::Debug);
+        assert_eq!(level(9, 0), LevelFilter::Trace);
+        assert_eq!(level(0, 1), LevelFilter::Error);
+        assert_eq!(level(0, 9), LevelFilter::Off);
+        assert_eq!(level(1, 1), LevelFilter::Warn);
+    }
+}
@@ -1,133 +1,803 @@
-#![feature(array_zip)]
-#![feature(int_abs_diff)]
-#![feature(bool_to_option)]
+mod logger;
 
-mod application;
-mod bin_seq;
-mod pop_array;
-
-use solver::ParsingError;
-use solver::SolvingError;
+use advent_solver::answer::Answer;
+use advent_solver::output::{self, Failure, FailureKind, Format, Location, Record};
+use advent_solver::param::{Assignment, Param, Params};
+use advent_solver::registry::{self, Part};
+use advent_solver::solver::{self, Exercice, SolvingError};
+use advent_solver::{answers, config, fetch, pool, runner, scaffold, server, submit};
+use derive_more::Display;
 use std::io::{self, BufRead, BufReader};
-use std::str::FromStr;
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use structopt::clap::{self, ErrorKind};
 use structopt::StructOpt;
 
-mod day_1;
-use day_1::First as Day1First;
-use day_1::Parser as Day1Parser;
-use day_1::Second as Day1Second;
+const EXIT_CODES: &str = "EXIT CODES:
+    1    Verification failed, answer rejected, solver panicked, or unexpected error
+    2    Input or answers file unreadable, or puzzle server unavailable
+    3    No solver for the requested day and part
+    4    Malformed input
+    5    No single solution for the input
+    6    Overflow while solving
+    7    Solver out of the time given by --timeout";
 
-mod day_2;
-use day_2::First as Day2First;
-use day_2::Parser as Day2Parser;
-use day_2::Second as Day2Second;
+#[derive(StructOpt)]
+struct Opt {
+    /// Year of the puzzles, the latest one with solutions otherwise
+    #[structopt(long, global = true)]
+    year: Option<u16>,
+    day: Option<usize>,
+    /// Only solve this part, both parts from a single parse otherwise
+    part: Option<Part>,
+    /// Input file, `-` for stdin, or a directory of inputs; repeat to solve several inputs
+    #[structopt(long)]
+    input: Vec<String>,
+    /// Solve the example of the day, or the `.example` files of the --input directories
+    #[structopt(long)]
+    example: bool,
+    /// Change a puzzle constant of the solvers, see PARAMETERS; repeat to change several
+    #[structopt(long = "param", value_name = "name=value", number_of_values = 1)]
+    params: Vec<Assignment>,
+    /// Give up on a part after this many seconds, its parse included for the first part
+    #[structopt(long, global = true, parse(try_from_str = seconds))]
+    timeout: Option<Duration>,
+    /// Solve this many days, or inputs, at once
+    #[structopt(short, long, global = true, default_value = "1")]
+    jobs: NonZeroUsize,
+    /// Print answers as text, or as one JSON record per part
+    #[structopt(long, global = true, default_value = "text", possible_values = &["text", "json"])]
+    format: Format,
+    /// Log solvers' intermediate state to stderr, more with each occurrence
+    #[structopt(short, long, global = true, parse(from_occurrences))]
+    verbose: u8,
+    /// Log less, nothing at all when repeated
+    #[structopt(short, long, global = true, parse(from_occurrences))]
+    quiet: u8,
+    #[structopt(subcommand)]
+    command: Option<Command>,
+}
 
-mod day_3;
-use day_3::First as Day3First;
-use day_3::Parser as Day3Parser;
-use day_3::Second as Day3Second;
+#[derive(StructOpt)]
+enum Command {
+    /// Solve every registered day and part, then print a results table
+    RunAll {
+        #[structopt(long)]
+        example: bool,
+    },
+    /// Run solvers repeatedly and report parse and solve timing statistics
+    Bench {
+        /// Only bench this day, every day otherwise
+        day: Option<usize>,
+        /// Only bench this part, both parts otherwise
+        part: Option<Part>,
+        #[structopt(short, long, default_value = "10")]
+        runs: usize,
+        #[structopt(long)]
+        example: bool,
+    },
+    /// Re-solve days and check their embedded examples and the answers of `answers/<year>/<day>.toml`,
+    /// failing when there is nothing to check
+    Verify {
+        /// Only verify this day, every day otherwise
+        day: Option<usize>,
+        /// Only verify against the example inputs
+        #[structopt(long)]
+        example: bool,
+    },
+    /// Download the personal input of a day into `inputs/<year>/<day>`, unless it is already there
+    Fetch { day: usize },
+    /// Solve a part of a day on its input and submit the answer, keeping the verdict in `answers/<year>/<day>.toml`
+    Submit { day: usize, part: Part },
+    /// Generate the module of a new day with an empty example file, and register it
+    New {
+        day: usize,
+        /// Name of the puzzle type, `Day<day>` otherwise
+        #[structopt(long)]
+        name: Option<String>,
+        /// Generate separate `Parse` and `Solver` implementations instead of a `Problem`
+        #[structopt(long)]
+        solvers: bool,
+    },
+}
 
-mod day_4;
-use day_4::First as Day4First;
-use day_4::Parser as Day4Parser;
-use day_4::Second as Day4Second;
+#[derive(Debug, Clone, Display)]
+enum Error {
+    #[display(fmt = "Can't open input file {}: {}", file_name, reason)]
+    CantOpenInputFile {
+        file_name: String,
+        reason: String,
+    },
+    #[display(fmt = "No solver registered for day {} part {}", day, part)]
+    NoCorrespondingSolver {
+        day: usize,
+        part: Part,
+    },
+    #[display(fmt = "No solver registered for day {}", _0)]
+    UnknownDay(usize),
+    #[display(fmt = "No solutions for year {}", _0)]
+    UnknownYear(u16),
+    #[display(fmt = "{}", "located(source, error)")]
+    SolverFailed {
+        source: String,
+        error: solver::Error,
+    },
+    AnswersUnavailable(String),
+    ScaffoldFailed(String),
+    FetchFailed(String),
+    SubmitFailed(String),
+    AnswerRejected(String),
+    #[display(fmt = "{} verification(s) failed", _0)]
+    VerificationFailed(usize),
+    #[display(fmt = "Nothing to verify, no example embedded nor answer recorded")]
+    NothingVerified,
+    /// Failures already printed as they happened
+    #[display(fmt = "{} failure(s)", _1)]
+    Reported(FailureKind, usize),
+}
 
-mod day_5;
-use day_5::First as Day5First;
+/// Parses a positive number of seconds, fractions included.
+fn seconds(s: &str) -> Result<Duration, String> {
+    match s.parse::<f64>().map(Duration::try_from_secs_f64) {
+        Ok(Ok(duration)) if !duration.is_zero() => Ok(duration),
+        _ => Err(format!(
+            "Invalid duration '{}', expected a positive number of seconds",
+            s
+        )),
+    }
+}
 
-mod day_6;
-use day_6::LanterfishCrew as Day6First;
+/// Prefixes `error` with the input it comes from, the way compilers do.
+fn located(source: &str, error: &solver::Error) -> String {
+    match error {
+        solver::Error::WrongLine(diagnostic) => format!("{}:{}", source, diagnostic),
+        error => format!("{}: {}", source, error),
+    }
+}
 
-mod day_7;
-use day_7::First;
+impl Error {
+    /// Decides the process exit code, see the `EXIT CODES` section of the help.
+    fn kind(&self) -> FailureKind {
+        match self {
+            Error::VerificationFailed(_) | Error::NothingVerified | Error::AnswerRejected(_) => {
+                FailureKind::VerificationFailed
+            }
+            Error::ScaffoldFailed(_) => FailureKind::Unexpected,
+            Error::Reported(kind, _) => *kind,
+            Error::CantOpenInputFile { .. }
+            | Error::AnswersUnavailable(_)
+            | Error::FetchFailed(_)
+            | Error::SubmitFailed(_) => FailureKind::InputUnavailable,
+            Error::NoCorrespondingSolver { .. } | Error::UnknownDay(_) | Error::UnknownYear(_) => FailureKind::NoSolver,
+            Error::SolverFailed { error, .. } => match error {
+                solver::Error::Solving(SolvingError::Unimplemented) => FailureKind::NoSolver,
+                solver::Error::Unreadable(_) => FailureKind::InputUnavailable,
+                solver::Error::EmptyFile | solver::Error::WrongLine(_) | solver::Error::ExpectationUnfulfilled(_) => {
+                    FailureKind::MalformedInput
+                }
+                solver::Error::Solving(SolvingError::NoSolution(_) | SolvingError::Ambiguous(_)) => {
+                    FailureKind::NoSolution
+                }
+                solver::Error::Solving(SolvingError::Overflow(_)) => FailureKind::Overflow,
+                solver::Error::Unexpected => FailureKind::Unexpected,
+                solver::Error::Panicked(_) => FailureKind::Panicked,
+                solver::Error::TimedOut(_) => FailureKind::TimedOut,
+            },
+        }
+    }
 
-mod day_8;
-use day_8::SevenSegmentSearch;
+    fn failure(&self) -> Failure {
+        let (message, location) = match self {
+            Error::SolverFailed {
+                source,
+                error: solver::Error::WrongLine(diagnostic),
+            } => (
+                diagnostic.message.clone(),
+                Some(Location {
+                    source: source.clone(),
+                    line: diagnostic.line_number,
+                    column: diagnostic.column + 1,
+                    width: diagnostic.width,
+                }),
+            ),
+            Error::SolverFailed { error, .. } => (error.to_string(), None),
+            e => (e.to_string(), None),
+        };
+        Failure {
+            kind: self.kind(),
+            message,
+            location,
+        }
+    }
 
-mod day_17;
-use day_17::ProbeSystem;
+    /// Renders the error for the terminal, quoting the offending input line when there is one.
+    fn render(&self) -> String {
+        match self {
+            Error::SolverFailed {
+                source,
+                error: solver::Error::WrongLine(diagnostic),
+            } => diagnostic.render(source),
+            e => format!("error: {}", e),
+        }
+    }
+}
 
-mod solver;
-use solver::Exercice;
+/// A puzzle input, read as it gets parsed, along with where it comes from.
+struct Input {
+    name: String,
+    /// Sendable, to be parsed on the thread of an isolated run
+    reader: Box<dyn BufRead + Send>,
+}
 
-mod schooler;
-use schooler::Schooler;
+impl Input {
+    /// Reads the whole input, for stages which go through it repeatedly.
+    fn text(&mut self) -> Result<String, Error> {
+        let mut text = String::new();
+        match self.reader.read_to_string(&mut text) {
+            Ok(_) => Ok(text),
+            Err(e) => Err(self.failure(e.into())),
+        }
+    }
 
-enum Part {
-    One,
-    Two,
+    fn failure(&self, error: solver::Error) -> Error {
+        Error::SolverFailed {
+            source: self.name.clone(),
+            error,
+        }
+    }
 }
 
-#[derive(StructOpt)]
-struct Opt {
+fn input_file_name(year: u16, day: usize, example: bool) -> String {
+    let ext = match example {
+        false => "",
+        true => ".example",
+    };
+    format!("inputs/{}/{}{}", year, day, ext)
+}
+
+fn cant_open(file_name: &str) -> impl Fn(io::Error) -> Error + '_ {
+    move |e| Error::CantOpenInputFile {
+        file_name: file_name.into(),
+        reason: e.to_string(),
+    }
+}
+
+/// Reads the input file `file_name`, or stdin when it is `-`.
+fn read_input(file_name: &str) -> Result<Input, Error> {
+    let (name, reader): (_, Box<dyn BufRead + Send>) = match file_name {
+        "-" => ("<stdin>", Box::new(BufReader::new(io::stdin()))),
+        _ => {
+            let file = std::fs::File::open(file_name).map_err(cant_open(file_name))?;
+            (file_name, Box::new(BufReader::new(file)))
+        }
+    };
+    Ok(Input {
+        name: name.into(),
+        reader,
+    })
+}
+
+/// Whether `path`, found in a directory of inputs, is one to solve: hidden files are partial
+/// downloads, and `.example` files are only solved with `--example`.
+fn listed(path: &Path, example: bool) -> bool {
+    let hidden = path.file_name().unwrap_or_default().to_string_lossy().starts_with('.');
+    let is_example = path.extension().is_some_and(|ext| ext == "example");
+    path.is_file() && !hidden && is_example == example
+}
+
+/// Input files named by `paths`, a directory standing for the inputs it holds, or for its examples.
+fn input_files(paths: Vec<String>, example: bool) -> Result<Vec<String>, Error> {
+    let mut files = vec![];
+    for path in paths {
+        if !Path::new(&path).is_dir() {
+            files.push(path);
+            continue;
+        }
+        let mut entries = std::fs::read_dir(&path)
+            .and_then(|entries| entries.collect::<Result<Vec<_>, io::Error>>())
+            .map_err(cant_open(&path))?
+            .into_iter()
+            .map(|entry| entry.path())
+            .filter(|path| listed(path, example))
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        entries.sort();
+        files.extend(entries);
+    }
+    Ok(files)
+}
+
+/// Reads the input of `day`, falling back on the embedded example when no example file exists.
+fn day_input(year: u16, day: usize, example: bool, exercice: &dyn Exercice) -> Result<Input, Error> {
+    let file_name = input_file_name(year, day, example);
+    match exercice.examples().first() {
+        Some(embedded) if example && !Path::new(&file_name).exists() => Ok(Input {
+            name: format!("<day {} example>", day),
+            reader: Box::new(embedded.reader()),
+        }),
+        _ => read_input(&file_name),
+    }
+}
+
+/// Outcome of one part, with the time spent in each stage it reached.
+struct Solved {
+    part: Part,
+    /// Name of the input, when it could be read
+    input: Option<String>,
+    parse_time: Option<Duration>,
+    solve_time: Option<Duration>,
+    outcome: Result<Answer, Error>,
+}
+
+impl Solved {
+    fn record(&self, year: u16, day: usize) -> Record {
+        Record {
+            year,
+            day,
+            part: self.part,
+            input: self.input.clone(),
+            answer: self.outcome.as_ref().ok().cloned(),
+            parse_time_ns: self.parse_time.map(output::nanoseconds),
+            solve_time_ns: self.solve_time.map(output::nanoseconds),
+            error: self.outcome.as_ref().err().map(Error::failure),
+        }
+    }
+}
+
+/// How the solves of a batch get run.
+#[derive(Clone, Copy)]
+struct Batch {
+    /// Time given to each part
+    budget: Option<Duration>,
+    /// Solves running at once, both parts of a day on an input being a single one
+    jobs: usize,
+}
+
+/// Solves `parts` of `day` from a single parse of the input, isolated and given `budget` each.
+fn solve_parts(
+    year: u16,
     day: usize,
-    part: usize,
-    #[structopt(long)]
+    parts: Vec<(Part, Arc<dyn Exercice>)>,
     input: Option<String>,
-    #[structopt(long, conflicts_with = "input")]
     example: bool,
+    budget: Option<Duration>,
+) -> Vec<Solved> {
+    let (parts, exercices): (Vec<_>, Vec<_>) = parts.into_iter().unzip();
+    let input = match input {
+        None => day_input(year, day, example, exercices[0].as_ref()),
+        Some(file_name) => read_input(&file_name),
+    };
+    match input {
+        Err(e) => parts
+            .into_iter()
+            .map(|part| Solved {
+                part,
+                input: None,
+                parse_time: None,
+                solve_time: None,
+                outcome: Err(e.clone()),
+            })
+            .collect(),
+        Ok(input) => parts
+            .into_iter()
+            .zip(runner::run_parts_isolated(exercices, input.reader, budget))
+            .map(|(part, report)| Solved {
+                part,
+                input: Some(input.name.clone()),
+                parse_time: Some(report.parse_time),
+                solve_time: report.solve_time,
+                outcome: report.outcome.map_err(|error| Error::SolverFailed {
+                    source: input.name.clone(),
+                    error,
+                }),
+            })
+            .collect(),
+    }
 }
 
-#[derive(Debug)]
-enum Error {
-    CantOpenInputFile(String),
-    ParsingFailed(solver::ParsingError),
-    NoCorrespondingSolver,
-    SolverFailed(solver::Error),
+/// Solutions of `year`.
+fn calendar(year: u16) -> Result<registry::Registry, Error> {
+    registry::calendar(year).ok_or(Error::UnknownYear(year))
+}
+
+/// `exercice`, set up for the puzzle examples when solving them.
+fn prepared(exercice: &Arc<dyn Exercice>, example: bool) -> Arc<dyn Exercice> {
+    match example {
+        true => exercice.for_examples().into(),
+        false => exercice.clone(),
+    }
+}
+
+/// The parts of `day`, to be handed over to isolated runs.
+fn shared(registry: &registry::Registry, day: usize, example: bool) -> Vec<(Part, Arc<dyn Exercice>)> {
+    registry
+        .day(day)
+        .into_iter()
+        .map(|(part, exercice)| (part, prepared(exercice, example)))
+        .collect()
+}
+
+fn run_all(year: u16, example: bool, format: Format, batch: Batch) -> Result<(), Error> {
+    let registry = calendar(year)?;
+    if format == Format::Text {
+        println!("{:>4} {:>4} {:>12} {:>12}  Answer", "Day", "Part", "Parse", "Solve");
+    }
+    let as_time = |time: Option<Duration>| time.map(|t| format!("{:.2?}", t)).unwrap_or_else(|| "-".into());
+    let solve_day = |day| {
+        let solved = solve_parts(year, day, shared(&registry, day, example), None, example, batch.budget);
+        (day, solved)
+    };
+    pool::ordered(registry.days(), batch.jobs, solve_day, |(day, solved)| {
+        for (index, solved) in solved.iter().enumerate() {
+            if format == Format::Json {
+                println!("{}", solved.record(year, day).to_json());
+                continue;
+            }
+            let answer = match &solved.outcome {
+                Ok(answer) => answer.to_string(),
+                Err(e) => format!("FAILED {}", e),
+            };
+            // Parts are solved from a single parse, timed once
+            let parse_time = match index {
+                0 => as_time(solved.parse_time),
+                _ => String::new(),
+            };
+            println!(
+                "{:>4} {:>4} {:>12} {:>12}  {}",
+                day,
+                solved.part,
+                parse_time,
+                as_time(solved.solve_time),
+                answer
+            );
+        }
+    });
+    Ok(())
+}
+
+fn bench(year: u16, day: Option<usize>, part: Option<Part>, runs: usize, example: bool) -> Result<(), Error> {
+    let registry = calendar(year)?;
+    println!(
+        "{:>4} {:>4} {:>6} {:>12} {:>12} {:>12} {:>12}",
+        "Day", "Part", "Stage", "Min", "Median", "Mean", "P95"
+    );
+    let selected = registry
+        .iter()
+        .filter(|(d, p, _)| day.is_none_or(|day| day == *d) && part.is_none_or(|part| part == *p));
+    for (day, part, exercice) in selected {
+        let exercice = prepared(exercice, example);
+        let exercice = exercice.as_ref();
+        let stats = day_input(year, day, example, exercice)
+            .and_then(|mut input| {
+                let text = input.text()?;
+                runner::bench(exercice, &text, runs).map_err(|e| input.failure(e))
+            });
+        match stats {
+            Err(e) => println!("{:>4} {:>4}  FAILED {}", day, part, e),
+            Ok(None) => println!("{:>4} {:>4}  No run", day, part),
+            Ok(Some((parse, solve))) => {
+                for (stage, stats) in [("parse", parse), ("solve", solve)] {
+                    println!(
+                        "{:>4} {:>4} {:>6} {:>12.2?} {:>12.2?} {:>12.2?} {:>12.2?}",
+                        day, part, stage, stats.min, stats.median, stats.mean, stats.p95
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
 }
 
-use solver::Problem;
-struct Example {}
+fn verify(year: u16, day: Option<usize>, example_only: bool, budget: Option<Duration>) -> Result<(), Error> {
+    let registry = calendar(year)?;
+    let kinds: &[bool] = match example_only {
+        false => &[false, true],
+        true => &[true],
+    };
+    let (mut checks, mut failures) = (0, 0);
+    for (day, part, exercice) in registry.iter().filter(|(d, _, _)| day.is_none_or(|day| day == *d)) {
+        let mut check = |kind: &str, outcome: Result<Answer, Error>, expected: &Answer| {
+            checks += 1;
+            match outcome {
+                Ok(answer) if &answer == expected => println!("{:>4} {:>4} {:>8} ok {}", day, part, kind, answer),
+                Ok(answer) => {
+                    failures += 1;
+                    println!(
+                        "{:>4} {:>4} {:>8} MISMATCH expected {}, got {}",
+                        day, part, kind, expected, answer
+                    )
+                }
+                Err(e) => {
+                    failures += 1;
+                    println!("{:>4} {:>4} {:>8} FAILED {}", day, part, kind, e)
+                }
+            }
+        };
+
+        for example in exercice.examples() {
+            let outcome = runner::run_isolated(prepared(exercice, true), Box::new(example.reader()), budget).outcome;
+            let outcome = outcome.map_err(|error| Error::SolverFailed {
+                source: format!("<day {} example>", day),
+                error,
+            });
+            check("embedded", outcome, &example.answer);
+        }
+
+        let answers = match answers::Answers::load(year, day) {
+            Ok(answers) => answers,
+            Err(e) => {
+                check("answers", Err(Error::AnswersUnavailable(e.to_string())), &"".into());
+                continue;
+            }
+        };
+        for &example in kinds {
+            // Without an example file, the embedded examples stand for it and were checked above
+            if example && !Path::new(&input_file_name(year, day, true)).exists() {
+                continue;
+            }
+            let expected = match answers.expected(part, example) {
+                Some(expected) => expected,
+                None => continue,
+            };
+            let kind = if example { "example" } else { "input" };
+            let outcome = day_input(year, day, example, exercice.as_ref()).and_then(|input| {
+                runner::run_isolated(prepared(exercice, example), input.reader, budget)
+                    .outcome
+                    .map_err(|error| Error::SolverFailed {
+                        source: input.name,
+                        error,
+                    })
+            });
+            check(kind, outcome, expected);
+        }
+    }
+    println!("{} checks, {} failures", checks, failures);
+    match (checks, failures) {
+        (0, _) => Err(Error::NothingVerified),
+        (_, 0) => Ok(()),
+        _ => Err(Error::VerificationFailed(failures)),
+    }
+}
+
+/// Solves the day given on the command line.
+fn solve(year: u16, opt: Opt, batch: Batch) -> Result<(), Error> {
+    let Opt {
+        day,
+        part,
+        input,
+        example,
+        format,
+        params,
+        ..
+    } = opt;
+    let day = match day {
+        Some(day) => day,
+        None => clap::Error::with_description("<day> is required", ErrorKind::MissingRequiredArgument).exit(),
+    };
 
-impl Problem for Example {
-    fn parse(lines: &[String]) -> Result<Self, ParsingError> {
-        todo!()
+    let registry = calendar(year)?;
+    let parts = match part {
+        None => shared(&registry, day, false),
+        Some(part) => {
+            let exercice = registry
+                .get(day, part)
+                .ok_or(Error::NoCorrespondingSolver { day, part })?;
+            vec![(part, exercice.clone())]
+        }
+    };
+    if parts.is_empty() {
+        return Err(Error::UnknownDay(day));
     }
+    let declared: Vec<_> = parts.iter().flat_map(|(_, exercice)| exercice.params()).collect();
+    let params = match Params::new(&params, &declared) {
+        Ok(params) if example => params.for_examples(&declared),
+        Ok(params) => params,
+        Err(e) => clap::Error::with_description(&e.to_string(), ErrorKind::InvalidValue).exit(),
+    };
+    let parts: Vec<_> = parts
+        .into_iter()
+        .map(|(part, exercice)| (part, Arc::from(exercice.with_params(&params))))
+        .collect();
+    let sources = match input.is_empty() {
+        true => vec![None],
+        false => input_files(input, example)?.into_iter().map(Some).collect(),
+    };
 
-    fn part_one(&self) -> Result<usize, SolvingError> {
-        todo!()
+    let (labelled, headed) = (part.is_none(), sources.len() > 1);
+    let (mut first_failure, mut failures) = (None, 0);
+    let solve_source = |source: Option<String>| {
+        let solved = solve_parts(year, day, parts.clone(), source.clone(), example, batch.budget);
+        (source, solved)
+    };
+    pool::ordered(sources, batch.jobs, solve_source, |(source, solved)| {
+        if format == Format::Text && headed {
+            println!("==> {} <==", source.as_deref().unwrap_or_default());
+        }
+        for solved in &solved {
+            match (format, &solved.outcome) {
+                (Format::Json, _) => println!("{}", solved.record(year, day).to_json()),
+                (Format::Text, Ok(answer)) if labelled => println!("Part {}: {}", solved.part, answer),
+                (Format::Text, Ok(answer)) => println!("{}", answer),
+                (Format::Text, Err(e)) => eprintln!("{}", e.render()),
+            }
+            if let Err(e) = &solved.outcome {
+                failures += 1;
+                first_failure.get_or_insert(e.kind());
+                // Both parts fail alike when reading or parsing the input does
+                if solved.solve_time.is_none() && format == Format::Text {
+                    break;
+                }
+            }
+        }
+    });
+    match first_failure {
+        None => Ok(()),
+        Some(kind) => Err(Error::Reported(kind, failures)),
     }
+}
 
-    fn part_two(&self) -> Result<usize, SolvingError> {
-        todo!()
+fn fetch_input(year: u16, day: usize) -> Result<(), Error> {
+    let config = config::Config::load().map_err(|e| Error::FetchFailed(e.to_string()))?;
+    let fetcher = fetch::Fetcher::new(server::Server::new(config, "inputs"), "inputs");
+    match fetcher.fetch(year, day) {
+        Ok(fetch::Fetched::Downloaded(file)) => println!("Downloaded {}", file),
+        Ok(fetch::Fetched::Cached(file)) => println!("Already fetched {}", file),
+        Err(e) => return Err(Error::FetchFailed(e.to_string())),
     }
-    //
+    Ok(())
 }
 
-fn solve<T: Problem>(lines: &[String], part: usize) -> Result<usize, Error> {
-    let problem = T::parse(&lines).map_err(|e| Error::ParsingFailed(e))?;
-    if part == 1 {
-        problem.part_one().map_err(|_| Error::NoCorrespondingSolver)
-    } else {
-        problem.part_two().map_err(|_| Error::NoCorrespondingSolver)
+/// Submits the answer of `part` of `day`, unless it is already known to be right or wrong.
+fn submit_answer(year: u16, day: usize, part: Part) -> Result<(), Error> {
+    let registry = calendar(year)?;
+    let exercice = registry
+        .get(day, part)
+        .map(AsRef::as_ref)
+        .ok_or(Error::NoCorrespondingSolver { day, part })?;
+    let mut input = day_input(year, day, false, exercice)?;
+    let answer = runner::run(exercice, &mut input.reader)
+        .outcome
+        .map_err(|e| input.failure(e))?;
+    if let Answer::Grid(_) = answer {
+        return Err(Error::SubmitFailed(format!(
+            "Read the letters of the answer and submit them by hand:\n{}",
+            answer
+        )));
+    }
+
+    let mut answers = answers::Answers::load(year, day).map_err(|e| Error::AnswersUnavailable(e.to_string()))?;
+    match answers.expected(part, false) {
+        Some(expected) if *expected == answer => {
+            println!("Part {}: {} was already accepted", part, answer);
+            return Ok(());
+        }
+        Some(expected) => {
+            let message = format!("Part {} was accepted as {}, not {}", part, expected, answer);
+            return Err(Error::AnswerRejected(message));
+        }
+        None => (),
+    }
+    if let Some(rejection) = answers.rejection(part, &answer) {
+        let message = format!(
+            "Part {}: {} is known to be {}, not submitting it",
+            part, answer, rejection
+        );
+        return Err(Error::AnswerRejected(message));
+    }
+
+    let config = config::Config::load().map_err(|e| Error::SubmitFailed(e.to_string()))?;
+    let server = server::Server::new(config, "inputs");
+    let verdict = submit::submit(&server, year, day, part, &answer).map_err(|e| Error::SubmitFailed(e.to_string()))?;
+    let save = |answers: &answers::Answers| {
+        answers
+            .save(year, day)
+            .map_err(|e| Error::AnswersUnavailable(e.to_string()))
+    };
+    match verdict {
+        submit::Verdict::Correct => {
+            answers.accept(part, answer.clone());
+            save(&answers)?;
+            println!("Part {}: {} is correct", part, answer);
+            Ok(())
+        }
+        submit::Verdict::Rejected(rejection) => {
+            answers.reject(part, answer.clone(), rejection);
+            save(&answers)?;
+            Err(Error::AnswerRejected(format!(
+                "Part {}: {} is {}",
+                part, answer, rejection
+            )))
+        }
+        submit::Verdict::RateLimited(wait) => Err(Error::SubmitFailed(match wait {
+            Some(wait) => format!("Answered too recently, {} left to wait", wait),
+            None => "Answered too recently".into(),
+        })),
+        submit::Verdict::WrongLevel => Err(Error::SubmitFailed(format!(
+            "Part {} can't be submitted, already solved or still locked",
+            part
+        ))),
     }
 }
 
-fn main() -> Result<(), Error> {
-    let opt = Opt::from_args();
+/// Scaffolds a new day in the current crate.
+fn new_day(year: u16, day: usize, name: Option<String>, solvers: bool) -> Result<(), Error> {
+    let written = scaffold::Scaffold::new(year, day, name, solvers)
+        .write(Path::new("."))
+        .map_err(|e| Error::ScaffoldFailed(e.to_string()))?;
+    for file in written {
+        println!("Wrote {}", file);
+    }
+    Ok(())
+}
 
-    let file_name = match opt.input {
-        None => {
-            let ext = match opt.example {
-                false => "",
-                true => ".example",
+/// Lists the parameters of every solver, for the help.
+fn params_help() -> String {
+    let mut help = String::from("PARAMETERS, changed with --param <name>=<value>:");
+    for &year in registry::YEARS {
+        let registry = match registry::calendar(year) {
+            Some(registry) => registry,
+            None => continue,
+        };
+        // A parameter of the parser is one of both parts
+        let mut listed: Vec<(usize, Param, Vec<Part>)> = vec![];
+        for (day, part, exercice) in registry.iter() {
+            for param in exercice.params() {
+                match listed.iter_mut().find(|(d, p, _)| *d == day && *p == param) {
+                    Some((_, _, parts)) => parts.push(part),
+                    None => listed.push((day, param, vec![part])),
+                }
+            }
+        }
+        for (day, param, parts) in listed {
+            let solver = match &parts[..] {
+                [part] => format!("{} day {} part {}", year, day, part),
+                _ => format!("{} day {}", year, day),
             };
-            format!("inputs/{}{}", opt.day, ext)
+            let example = param.example.map(|value| format!(", {} in the examples", value));
+            help.push_str(&format!(
+                "\n    {:<18} {:<10} {}, {}{}",
+                solver,
+                param.to_string(),
+                param.help,
+                param.range(),
+                example.unwrap_or_default()
+            ));
         }
-        Some(file_name) => file_name,
+    }
+    help
+}
+
+fn main() {
+    let after_help = format!("{}\n\n{}", EXIT_CODES, params_help());
+    let mut opt = Opt::from_clap(&Opt::clap().after_help(after_help.as_str()).get_matches());
+    logger::init(logger::level(opt.verbose, opt.quiet));
+
+    let year = opt.year.unwrap_or(registry::YEARS[registry::YEARS.len() - 1]);
+    let batch = Batch {
+        budget: opt.timeout,
+        jobs: opt.jobs.get(),
     };
-    let file = std::fs::File::open(file_name).map_err(|e| Error::CantOpenInputFile(e.to_string()))?;
-    let lines = BufReader::new(file)
-        .lines()
-        .collect::<Result<Vec<_>, io::Error>>()
-        .unwrap();
-
-    let solution = match opt.day {
-        8 => solve::<SevenSegmentSearch>(&lines, opt.part)?,
-        17 => solve::<ProbeSystem>(&lines, opt.part)?,
-        _ => return Err(Error::NoCorrespondingSolver),
+    let result = match opt.command.take() {
+        Some(Command::RunAll { example }) => run_all(year, example, opt.format, batch),
+        Some(Command::Bench {
+            day,
+            part,
+            runs,
+            example,
+        }) => bench(year, day, part, runs, example),
+        Some(Command::Verify { day, example }) => verify(year, day, example, opt.timeout),
+        Some(Command::Fetch { day }) => fetch_input(year, day),
+        Some(Command::Submit { day, part }) => submit_answer(year, day, part),
+        Some(Command::New { day, name, solvers }) => new_day(year, day, name, solvers),
+        None => solve(year, opt, batch),
     };
-    println!("{}", solution);
 
-    Ok(())
+    if let Err(e) = result {
+        if !matches!(e, Error::Reported(..)) {
+            eprintln!("{}", e.render());
+        }
+        std::process::exit(e.kind().exit_code());
+    }
 }
@@ -0,0 +1,134 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use derive_more::Display;
+use serde::Serialize;
+
+use crate::answer::Answer;
+use crate::registry::Part;
+
+/// How runs get printed: for people, or for scripts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    /// One JSON record per line
+    Json,
+}
+
+#[derive(Debug, Display)]
+#[display(fmt = "Unknown format '{}', expected text or json", _0)]
+pub struct UnknownFormat(String);
+
+impl FromStr for Format {
+    type Err = UnknownFormat;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(UnknownFormat(s.into())),
+        }
+    }
+}
+
+/// What went wrong, as far as scripts are concerned. It also decides the process exit code.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureKind {
+    Unexpected,
+    VerificationFailed,
+    InputUnavailable,
+    NoSolver,
+    MalformedInput,
+    NoSolution,
+    Overflow,
+    Panicked,
+    TimedOut,
+}
+
+impl FailureKind {
+    pub fn exit_code(self) -> i32 {
+        match self {
+            FailureKind::Unexpected | FailureKind::VerificationFailed | FailureKind::Panicked => 1,
+            FailureKind::InputUnavailable => 2,
+            FailureKind::NoSolver => 3,
+            FailureKind::MalformedInput => 4,
+            FailureKind::NoSolution => 5,
+            FailureKind::Overflow => 6,
+            FailureKind::TimedOut => 7,
+        }
+    }
+}
+
+/// Where in the input a failure is, with 1-based line and column.
+#[derive(Debug, Serialize)]
+pub struct Location {
+    pub source: String,
+    pub line: usize,
+    pub column: usize,
+    pub width: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Failure {
+    pub kind: FailureKind,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+}
+
+/// The outcome of solving one part, as printed by `--format json`.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: usize,
+    pub part: Part,
+    /// Name of the input file, `<stdin>` or `<day N example>`
+    pub input: Option<String>,
+    pub answer: Option<Answer>,
+    pub parse_time_ns: Option<u64>,
+    pub solve_time_ns: Option<u64>,
+    pub error: Option<Failure>,
+}
+
+pub fn nanoseconds(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+impl Record {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Records only hold serializable values")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_writes_records_on_one_line() {
+        let record = Record {
+            year: 2021,
+            day: 5,
+            part: Part::Two,
+            input: Some("inputs/5".into()),
+            answer: None,
+            parse_time_ns: Some(1200),
+            solve_time_ns: None,
+            error: Some(Failure {
+                kind: FailureKind::MalformedInput,
+                message: "Not a digit: 'x'".into(),
+                location: Some(Location {
+                    source: "inputs/5".into(),
+                    line: 3,
+                    column: 8,
+                    width: 1,
+                }),
+            }),
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2021,"day":5,"part":2,"input":"inputs/5","answer":null,"parse_time_ns":1200,"solve_time_ns":null,"error":{"kind":"malformed_input","message":"Not a digit: 'x'","location":{"source":"inputs/5","line":3,"column":8,"width":1}}}"#
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use derive_more::Display;
+use itertools::Itertools;
+
+/// A puzzle constant a solver lets `--param <name>=<value>` change.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Param {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub default: usize,
+    pub min: usize,
+    /// Largest value the solver copes with, if any
+    pub max: Option<usize>,
+    /// Value of the puzzle examples, when it differs from the one of the puzzle inputs
+    pub example: Option<usize>,
+}
+
+impl Param {
+    fn accepts(&self, value: usize) -> bool {
+        value >= self.min && self.max.is_none_or(|max| value <= max)
+    }
+
+    /// The values the parameter accepts, in words.
+    pub fn range(&self) -> String {
+        match (self.min, self.max) {
+            (min, Some(max)) => format!("{} to {}", min, max),
+            (0, None) => "any number".into(),
+            (min, None) => format!("at least {}", min),
+        }
+    }
+}
+
+impl Display for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.name, self.default)
+    }
+}
+
+/// A `<name>=<value>` of the command line, its value still to be checked.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assignment {
+    pub name: String,
+    pub value: String,
+}
+
+impl FromStr for Assignment {
+    type Err = ParamError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((name, value)) if !name.is_empty() => Ok(Assignment {
+                name: name.into(),
+                value: value.into(),
+            }),
+            _ => Err(ParamError::Malformed(s.into())),
+        }
+    }
+}
+
+#[derive(Debug, Display, PartialEq)]
+pub enum ParamError {
+    #[display(fmt = "Expected <name>=<value>, found '{}'", _0)]
+    Malformed(String),
+    #[display(fmt = "Unknown parameter '{}', expected one of: {}", _0, _1)]
+    Unknown(String, String),
+    #[display(fmt = "No parameter expected, found '{}'", _0)]
+    Unexpected(String),
+    #[display(fmt = "Invalid value '{}' for {}, expected {}", value, name, expected)]
+    Invalid {
+        name: String,
+        value: String,
+        expected: String,
+    },
+}
+
+/// Values of parameters, the ones left out keeping their default.
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    values: BTreeMap<String, usize>,
+}
+
+impl Params {
+    /// Checks `assignments` against the parameters `declared`, which may declare the same name
+    /// more than once, each part of a day having its own default for instance.
+    pub fn new(assignments: &[Assignment], declared: &[Param]) -> Result<Params, ParamError> {
+        let mut values = BTreeMap::new();
+        for Assignment { name, value } in assignments {
+            let params: Vec<_> = declared.iter().filter(|param| param.name == name).collect();
+            if params.is_empty() && declared.is_empty() {
+                return Err(ParamError::Unexpected(name.clone()));
+            }
+            if params.is_empty() {
+                let names = declared.iter().map(|param| param.name).unique().join(", ");
+                return Err(ParamError::Unknown(name.clone(), names));
+            }
+            match value.parse() {
+                Ok(number) if params.iter().all(|param| param.accepts(number)) => {
+                    values.insert(name.clone(), number);
+                }
+                _ => {
+                    return Err(ParamError::Invalid {
+                        name: name.clone(),
+                        value: value.clone(),
+                        expected: params.iter().map(|param| param.range()).unique().join(" and "),
+                    })
+                }
+            }
+        }
+        Ok(Params { values })
+    }
+
+    /// Falls back on the example values of the parameters `declared` rather than on their
+    /// defaults, for solving puzzle examples.
+    pub fn for_examples(mut self, declared: &[Param]) -> Params {
+        for param in declared {
+            if let Some(example) = param.example {
+                self.values.entry(param.name.into()).or_insert(example);
+            }
+        }
+        self
+    }
+
+    /// Value of `param`, its default unless assigned.
+    pub fn get(&self, param: &Param) -> usize {
+        self.values.get(param.name).copied().unwrap_or(param.default)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DAYS: Param = Param {
+        name: "days",
+        help: "Days to simulate",
+        default: 80,
+        min: 0,
+        max: None,
+        example: None,
+    };
+
+    const BITS: Param = Param {
+        name: "bits",
+        help: "Bits per number",
+        default: 12,
+        min: 1,
+        max: Some(32),
+        example: Some(5),
+    };
+
+    fn params(assignments: &[&str]) -> Result<Params, ParamError> {
+        let assignments: Vec<Assignment> = assignments.iter().map(|a| a.parse().unwrap()).collect();
+        Params::new(&assignments, &[DAYS, BITS])
+    }
+
+    #[test]
+    fn it_reads_assignments() {
+        assert_eq!(
+            Assignment::from_str("days=1000"),
+            Ok(Assignment {
+                name: "days".into(),
+                value: "1000".into()
+            })
+        );
+        assert_eq!(Assignment::from_str("days"), Err(ParamError::Malformed("days".into())));
+    }
+
+    #[test]
+    fn it_defaults_unassigned_params() {
+        let params = params(&["days=1000"]).unwrap();
+        assert_eq!(params.get(&DAYS), 1000);
+        assert_eq!(params.get(&BITS), 12);
+    }
+
+    #[test]
+    fn it_gives_examples_their_own_values() {
+        let examples = params(&["days=18"]).unwrap().for_examples(&[DAYS, BITS]);
+        assert_eq!(examples.get(&DAYS), 18);
+        assert_eq!(examples.get(&BITS), 5);
+        let examples = params(&["bits=8"]).unwrap().for_examples(&[DAYS, BITS]);
+        assert_eq!(examples.get(&BITS), 8);
+    }
+
+    #[test]
+    fn it_checks_values_against_declarations() {
+        assert_eq!(
+            params(&["bits=64"]).unwrap_err().to_string(),
+            "Invalid value '64' for bits, expected 1 to 32"
+        );
+        assert!(matches!(params(&["days=-1"]), Err(ParamError::Invalid { .. })));
+        assert_eq!(
+            params(&["window=3"]).unwrap_err(),
+            ParamError::Unknown("window".into(), "days, bits".into())
+        );
+        let assignments = ["days=3".parse().unwrap()];
+        assert_eq!(
+            Params::new(&assignments, &[]).unwrap_err(),
+            ParamError::Unexpected("days".into())
+        );
+    }
+}
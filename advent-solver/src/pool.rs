@@ -0,0 +1,60 @@
+use std::collections::BTreeMap;
+use std::sync::{mpsc, Mutex};
+use std::thread;
+
+/// Runs `task` over `tasks` on `jobs` threads, handing each result to `done` in the order of the
+/// tasks, as soon as the ones before it are done.
+pub fn ordered<T, R>(tasks: Vec<T>, jobs: usize, task: impl Fn(T) -> R + Sync, mut done: impl FnMut(R))
+where
+    T: Send,
+    R: Send,
+{
+    let queue = Mutex::new(tasks.into_iter().enumerate());
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            let (queue, task, sender) = (&queue, &task, sender.clone());
+            scope.spawn(move || {
+                while let Some((index, next)) = queue.lock().ok().and_then(|mut queue| queue.next()) {
+                    if sender.send((index, task(next))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        // Results coming early wait for the ones before them
+        let mut pending = BTreeMap::new();
+        let mut next = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&next) {
+                done(result);
+                next += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn it_hands_results_back_in_order() {
+        let mut results = vec![];
+        ordered(
+            (0..8u64).collect(),
+            4,
+            |n| {
+                // The first tasks are the slowest
+                thread::sleep(Duration::from_millis(40 - 5 * n));
+                n * n
+            },
+            |square| results.push(square),
+        );
+        assert_eq!(results, [0, 1, 4, 9, 16, 25, 36, 49]);
+    }
+}
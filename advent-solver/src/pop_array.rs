@@ -1,8 +1,22 @@
+use derive_more::Display;
+
+#[derive(Debug, Display, PartialEq)]
 pub enum PopArrayError {
+    #[display(fmt = "Empty arrays are not allowed")]
     EmptyArrayNotAllowed,
+    #[display(fmt = "Missing {} items", _0)]
     Missing(usize),
 }
 
+/// Takes fixed-size arrays out of an iterator.
+///
+/// ```
+/// use advent_solver::pop_array::{PopArray, PopArrayError};
+///
+/// let mut digits = [3, 1, 4, 1, 5].into_iter();
+/// assert_eq!(digits.pop_array::<2>(), Ok([3, 1]));
+/// assert_eq!(digits.pop_array::<4>(), Err(PopArrayError::Missing(1)));
+/// ```
 pub trait PopArray<T> {
     fn pop_array<const N: usize>(&mut self) -> Result<[T; N], PopArrayError>;
 }
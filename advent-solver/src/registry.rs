@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use derive_more::Display;
+use itertools::Itertools;
+use serde::{Serialize, Serializer};
+
+use crate::solver::Exercice;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+#[derive(Debug, Display)]
+#[display(fmt = "Unknown part '{}', expected 1 or 2", _0)]
+pub struct UnknownPart(String);
+
+impl FromStr for Part {
+    type Err = UnknownPart;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(UnknownPart(s.into())),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Part::One => serializer.serialize_u8(1),
+            Part::Two => serializer.serialize_u8(2),
+        }
+    }
+}
+
+/// Years with solutions, in order. Puzzles of the last one are solved unless told otherwise.
+pub const YEARS: &[u16] = &[2021];
+
+/// Every solver reachable from the command line, indexed by day and part.
+///
+/// Both parts of a day share their parser, so that one parsed model is lent to both. Exercices are
+/// shared, to be cloned onto the threads of isolated runs.
+#[derive(Default)]
+pub struct Registry {
+    exercices: BTreeMap<(usize, Part), Arc<dyn Exercice>>,
+}
+
+impl Registry {
+    pub fn register(&mut self, day: usize, part: Part, exercice: Box<dyn Exercice>) -> &mut Self {
+        self.exercices.insert((day, part), exercice.into());
+        self
+    }
+
+    pub fn get(&self, day: usize, part: Part) -> Option<&Arc<dyn Exercice>> {
+        self.exercices.get(&(day, part))
+    }
+
+    /// Registered parts of `day`, in order.
+    pub fn day(&self, day: usize) -> Vec<(Part, &Arc<dyn Exercice>)> {
+        self.exercices
+            .range((day, Part::One)..=(day, Part::Two))
+            .map(|((_, part), exercice)| (*part, exercice))
+            .collect()
+    }
+
+    pub fn days(&self) -> Vec<usize> {
+        self.exercices.keys().map(|(day, _)| *day).dedup().collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, Part, &Arc<dyn Exercice>)> {
+        self.exercices
+            .iter()
+            .map(|((day, part), exercice)| (*day, *part, exercice))
+    }
+}
+
+/// Solutions of `year`, if any.
+pub fn calendar(year: u16) -> Option<Registry> {
+    match year {
+        2021 => Some(crate::year_2021::calendar()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_parses_part() {
+        assert_eq!(Part::from_str("1").unwrap(), Part::One);
+        assert_eq!(Part::from_str("2").unwrap(), Part::Two);
+        assert!(Part::from_str("3").is_err());
+    }
+}
@@ -0,0 +1,342 @@
+use std::cell::RefCell;
+use std::io::BufRead;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::solver::{Error, Exercice};
+
+/// Outcome of one exercice run, with the time spent in each stage.
+pub struct Report {
+    pub parse_time: Duration,
+    pub solve_time: Option<Duration>,
+    pub outcome: Result<Answer, Error>,
+}
+
+pub fn run(exercice: &dyn Exercice, input: &mut dyn BufRead) -> Report {
+    run_parts(&[exercice], input).remove(0)
+}
+
+/// Parses `input` once and lends the model to each exercice in turn, so they must share the same parser.
+///
+/// Every report carries the single parse time, and the parse error if parsing failed.
+pub fn run_parts(exercices: &[&dyn Exercice], input: &mut dyn BufRead) -> Vec<Report> {
+    let start = Instant::now();
+    let model = match exercices.first() {
+        Some(exercice) => exercice.parse(input),
+        None => return vec![],
+    };
+    let parse_time = start.elapsed();
+
+    exercices
+        .iter()
+        .map(|exercice| match &model {
+            Err(e) => Report {
+                parse_time,
+                solve_time: None,
+                outcome: Err(e.clone()),
+            },
+            Ok(model) => {
+                let start = Instant::now();
+                let outcome = exercice.solve(model);
+                Report {
+                    parse_time,
+                    solve_time: Some(start.elapsed()),
+                    outcome,
+                }
+            }
+        })
+        .collect()
+}
+
+/// Name of the threads of isolated runs, whose panics are captured rather than printed.
+const ISOLATED_THREAD: &str = "isolated-solver";
+
+thread_local! {
+    /// Where the last panic of an isolated thread happened, and its message
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Has panics of isolated threads recorded for their reports, other panics still going to stderr.
+fn capture_panics() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() != Some(ISOLATED_THREAD) {
+                return default_hook(info);
+            }
+            let payload = info.payload();
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("Box<dyn Any>");
+            let panic = match info.location() {
+                Some(location) => format!("{}: {}", location, message),
+                None => message.to_string(),
+            };
+            LAST_PANIC.with(|last| *last.borrow_mut() = Some(panic));
+        }));
+    });
+}
+
+/// Runs `stage`, turning a panic into an error.
+fn isolate<T>(stage: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+    panic::catch_unwind(AssertUnwindSafe(stage)).unwrap_or_else(|_| {
+        let panic = LAST_PANIC.with(|last| last.borrow_mut().take());
+        Err(Error::Panicked(panic.unwrap_or_default()))
+    })
+}
+
+pub fn run_isolated(exercice: Arc<dyn Exercice>, input: Box<dyn BufRead + Send>, budget: Option<Duration>) -> Report {
+    run_parts_isolated(vec![exercice], input, budget).remove(0)
+}
+
+/// Same as [`run_parts`], on a thread of its own so that a panic fails the stage it happens in only,
+/// and each part gets at most `budget` to be solved, parsing included for the first one.
+///
+/// A part running past its budget is reported timed out along with the parts after it. Its thread
+/// can't be stopped and is left behind, still running.
+pub fn run_parts_isolated(
+    exercices: Vec<Arc<dyn Exercice>>,
+    mut input: Box<dyn BufRead + Send>,
+    budget: Option<Duration>,
+) -> Vec<Report> {
+    let count = exercices.len();
+    if count == 0 {
+        return vec![];
+    }
+    capture_panics();
+    let (sender, receiver) = mpsc::channel();
+    let spawned = thread::Builder::new().name(ISOLATED_THREAD.into()).spawn(move || {
+        let start = Instant::now();
+        let model = isolate(|| exercices[0].parse(&mut input));
+        let parse_time = start.elapsed();
+        for exercice in &exercices {
+            let report = match &model {
+                Err(e) => Report {
+                    parse_time,
+                    solve_time: None,
+                    outcome: Err(e.clone()),
+                },
+                Ok(model) => {
+                    let start = Instant::now();
+                    let outcome = isolate(|| exercice.solve(model));
+                    Report {
+                        parse_time,
+                        solve_time: Some(start.elapsed()),
+                        outcome,
+                    }
+                }
+            };
+            // Nobody waits for the report anymore once timed out
+            if sender.send(report).is_err() {
+                break;
+            }
+        }
+    });
+    if spawned.is_err() {
+        return (0..count).map(|_| failed(Duration::ZERO, Error::Unexpected)).collect();
+    }
+
+    let mut reports = Vec::with_capacity(count);
+    let mut stage_start = Instant::now();
+    while reports.len() < count {
+        let received = match budget {
+            Some(budget) => receiver.recv_timeout(budget.saturating_sub(stage_start.elapsed())),
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        let error = match received {
+            Ok(report) => {
+                reports.push(report);
+                stage_start = Instant::now();
+                continue;
+            }
+            Err(RecvTimeoutError::Timeout) => Error::TimedOut(budget.unwrap_or_default()),
+            Err(RecvTimeoutError::Disconnected) => Error::Unexpected,
+        };
+        let elapsed = stage_start.elapsed();
+        reports.resize_with(count, || failed(elapsed, error.clone()));
+    }
+    reports
+}
+
+/// Report of a run giving up after `elapsed`, not knowing how far it got.
+fn failed(elapsed: Duration, error: Error) -> Report {
+    Report {
+        parse_time: elapsed,
+        solve_time: None,
+        outcome: Err(error),
+    }
+}
+
+/// Timing statistics over repeated runs of the same stage.
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+        let len = samples.len();
+        let median = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+        // Nearest-rank percentile
+        let p95 = samples[(len * 95).div_ceil(100) - 1];
+        let mean = samples.iter().sum::<Duration>() / len as u32;
+        Some(Stats {
+            min: samples[0],
+            median,
+            mean,
+            p95,
+        })
+    }
+}
+
+/// Runs the exercice `runs` times, returning parse and solve statistics.
+pub fn bench(exercice: &dyn Exercice, text: &str, runs: usize) -> Result<Option<(Stats, Stats)>, Error> {
+    let mut parse_times = vec![];
+    let mut solve_times = vec![];
+    for _ in 0..runs {
+        let report = run(exercice, &mut text.as_bytes());
+        report.outcome?;
+        parse_times.push(report.parse_time);
+        solve_times.extend(report.solve_time);
+    }
+    Ok(Stats::from_samples(parse_times).zip(Stats::from_samples(solve_times)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::param::{Param, Params};
+    use crate::registry::Part;
+    use crate::solver::{Example, Model};
+    use crate::year_2021::calendar;
+    use std::io::{self, BufReader, Read};
+
+    fn ms(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn it_computes_stats() {
+        let stats = Stats::from_samples(ms(&[4, 1, 3, 2])).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.p95, Duration::from_millis(4));
+    }
+
+    #[test]
+    fn it_takes_nearest_rank_percentile() {
+        let stats = Stats::from_samples(ms(&(1..=100).collect::<Vec<_>>())).unwrap();
+        assert_eq!(stats.median, Duration::from_micros(50500));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+    }
+
+    #[test]
+    fn it_has_no_stats_without_samples() {
+        assert_eq!(Stats::from_samples(vec![]), None);
+    }
+
+    /// Breaks down as a closed pipe would.
+    struct Broken;
+
+    impl Read for Broken {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed pipe"))
+        }
+    }
+
+    #[test]
+    fn it_reports_inputs_breaking_while_parsed() {
+        let registry = calendar();
+        let exercice = registry.get(1, Part::One).unwrap().as_ref();
+        let mut input = BufReader::new("199\n200\n".as_bytes().chain(Broken));
+        assert!(matches!(run(exercice, &mut input).outcome, Err(Error::Unreadable(_))));
+    }
+
+    /// Solves one way or another, whatever the input.
+    #[derive(Clone)]
+    enum Faulty {
+        Panicking,
+        Sleeping,
+        Answering,
+    }
+
+    impl Exercice for Faulty {
+        fn parse(&self, _input: &mut dyn BufRead) -> Result<Model, Error> {
+            Ok(Box::new(()))
+        }
+
+        fn solve(&self, _model: &Model) -> Result<Answer, Error> {
+            match self {
+                Faulty::Panicking => panic!("no solution in sight"),
+                Faulty::Sleeping => thread::sleep(Duration::from_secs(3600)),
+                Faulty::Answering => (),
+            }
+            Ok(42.into())
+        }
+
+        fn examples(&self) -> Vec<Example<Answer>> {
+            vec![]
+        }
+
+        fn params(&self) -> Vec<Param> {
+            vec![]
+        }
+
+        fn with_params(&self, _params: &Params) -> Box<dyn Exercice> {
+            Box::new(self.clone())
+        }
+    }
+
+    #[test]
+    fn it_reports_panics_of_isolated_parts() {
+        let exercices: Vec<Arc<dyn Exercice>> = vec![Arc::new(Faulty::Panicking), Arc::new(Faulty::Answering)];
+        let reports = run_parts_isolated(exercices, Box::new("".as_bytes()), None);
+        match &reports[0].outcome {
+            Err(Error::Panicked(panic)) => {
+                assert!(panic.starts_with(file!()), "{}", panic);
+                assert!(panic.ends_with(": no solution in sight"), "{}", panic);
+            }
+            outcome => panic!("{:?}", outcome),
+        }
+        assert_eq!(reports[1].outcome.as_ref().unwrap(), &42.into());
+    }
+
+    #[test]
+    fn it_gives_up_on_parts_out_of_budget() {
+        let budget = Duration::from_millis(50);
+        let exercices: Vec<Arc<dyn Exercice>> = vec![Arc::new(Faulty::Sleeping), Arc::new(Faulty::Answering)];
+        let reports = run_parts_isolated(exercices, Box::new("".as_bytes()), Some(budget));
+        assert_eq!(reports.len(), 2);
+        for report in reports {
+            assert!(matches!(report.outcome, Err(Error::TimedOut(timeout)) if timeout == budget));
+            assert_eq!(report.solve_time, None);
+        }
+
+        let exercice = calendar().get(6, Part::One).unwrap().clone();
+        let report = run_isolated(
+            exercice,
+            Box::new("3,4,3,1,2".as_bytes()),
+            Some(Duration::from_secs(60)),
+        );
+        assert_eq!(report.outcome.unwrap(), 5934.into());
+    }
+}
@@ -0,0 +1,382 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use derive_more::Display;
+
+#[derive(Debug, Display)]
+pub enum ScaffoldError {
+    #[display(fmt = "Day {} already exists in {}", _0, _1)]
+    AlreadyExists(usize, String),
+    #[display(fmt = "Can't find where to declare day {} in {}", _0, _1)]
+    NoAnchor(usize, String),
+    #[display(fmt = "Can't read {}: {}", _0, _1)]
+    Unreadable(String, io::Error),
+    #[display(fmt = "Can't write {}: {}", _0, _1)]
+    Unwritable(String, io::Error),
+}
+
+const PROBLEM: &str = r#"use std::io::BufRead;
+
+use crate::answer::Answer;
+use crate::solver::{Example, ParsingError, Problem, SolvingError};
+
+#[derive(Debug)]
+pub struct $name {}
+
+impl Problem for $name {
+    fn parse(_input: &mut dyn BufRead) -> Result<Self, ParsingError> {
+        Ok($name {})
+    }
+
+    fn part_one(&self) -> Result<Answer, SolvingError> {
+        Err(SolvingError::Unimplemented)
+    }
+
+    fn part_two(&self) -> Result<Answer, SolvingError> {
+        Err(SolvingError::Unimplemented)
+    }
+
+    fn examples_part_one() -> Vec<Example<Answer>> {
+        // Declare the example once solved, along with the answer the puzzle gives for it:
+        // vec![Example { input: include_str!("../../inputs/$year/$day.example"), answer: ... }]
+        vec![]
+    }
+
+    fn examples_part_two() -> Vec<Example<Answer>> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_solves_the_examples() {
+        for example in $name::examples_part_one() {
+            let problem = $name::parse(&mut example.reader()).unwrap();
+            assert_eq!(problem.part_one().unwrap(), example.answer);
+        }
+        for example in $name::examples_part_two() {
+            let problem = $name::parse(&mut example.reader()).unwrap();
+            assert_eq!(problem.part_two().unwrap(), example.answer);
+        }
+    }
+}
+"#;
+
+const SOLVERS: &str = r#"use std::io::BufRead;
+
+use crate::solver::{Error, Example, Parse, Solver, SolvingError};
+
+#[derive(Debug)]
+pub struct $name {}
+
+#[derive(Default)]
+pub struct Parser {}
+
+impl Parse for Parser {
+    type ProblemModel = $name;
+
+    fn parse(&self, _input: &mut dyn BufRead) -> Result<Self::ProblemModel, Error> {
+        Err(SolvingError::Unimplemented.into())
+    }
+}
+
+#[derive(Default)]
+pub struct First {}
+
+impl Solver for First {
+    type ProblemModel = $name;
+
+    type Solution = usize;
+
+    fn solve(&self, _model: &Self::ProblemModel) -> Result<Self::Solution, Error> {
+        Err(SolvingError::Unimplemented.into())
+    }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        // Declare the example once solved, along with the answer the puzzle gives for it:
+        // vec![Example { input: include_str!("../../inputs/$year/$day.example"), answer: ... }]
+        vec![]
+    }
+}
+
+#[derive(Default)]
+pub struct Second {}
+
+impl Solver for Second {
+    type ProblemModel = $name;
+
+    type Solution = usize;
+
+    fn solve(&self, _model: &Self::ProblemModel) -> Result<Self::Solution, Error> {
+        Err(SolvingError::Unimplemented.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_solves_the_examples() {
+        for example in First::default().examples() {
+            let model = Parser::default().parse(&mut example.reader()).unwrap();
+            assert_eq!(First::default().solve(&model).unwrap(), example.answer);
+        }
+        for example in Second::default().examples() {
+            let model = Parser::default().parse(&mut example.reader()).unwrap();
+            assert_eq!(Second::default().solve(&model).unwrap(), example.answer);
+        }
+    }
+}
+"#;
+
+/// Declares the public module `{prefix}{number}` in `source`, among its siblings and in order.
+fn declare(source: &str, prefix: &str, number: usize) -> Option<String> {
+    let declaration = format!("pub mod {}{};\n", prefix, number);
+    let number_of = |line: &str| {
+        line.strip_prefix("pub mod ")
+            .and_then(|rest| rest.strip_prefix(prefix))
+            .and_then(|rest| rest.strip_suffix(';'))
+            .and_then(|n| n.parse::<usize>().ok())
+    };
+    let mut offset = 0;
+    let mut last_sibling_end = None;
+    for line in source.split_inclusive('\n') {
+        match number_of(line.trim_end()) {
+            Some(n) if n > number => break,
+            Some(_) => last_sibling_end = Some(offset + line.len()),
+            None => (),
+        }
+        offset += line.len();
+    }
+    let first_sibling = format!("\npub mod {}", prefix);
+    let at = last_sibling_end.or_else(|| source.find(&first_sibling).map(|i| i + 1))?;
+    Some(format!("{}{}{}", &source[..at], declaration, &source[at..]))
+}
+
+/// A new day: its module, its example file, and its entries in the calendar of its year. The
+/// year gets its own module and calendar when it is the first day of it.
+pub struct Scaffold {
+    year: u16,
+    day: usize,
+    name: String,
+    /// Separate `Parse` and `Solver` implementations rather than a `Problem`
+    solvers: bool,
+}
+
+impl Scaffold {
+    pub fn new(year: u16, day: usize, name: Option<String>, solvers: bool) -> Self {
+        Self {
+            year,
+            day,
+            name: name.unwrap_or_else(|| format!("Day{}", day)),
+            solvers,
+        }
+    }
+
+    fn module_name(&self) -> String {
+        format!("day_{}", self.day)
+    }
+
+    fn year_module_name(&self) -> String {
+        format!("year_{}", self.year)
+    }
+
+    pub fn module(&self) -> String {
+        let template = if self.solvers { SOLVERS } else { PROBLEM };
+        template
+            .replace("$year", &self.year.to_string())
+            .replace("$day", &self.day.to_string())
+            .replace("$name", &self.name)
+    }
+
+    /// Calls registering both parts, the way the calendar chains them.
+    fn entries(&self) -> String {
+        let module = self.module_name();
+        let (first, second) = if self.solvers {
+            (
+                format!("{}::Parser, {}::First", module, module),
+                format!("{}::Parser, {}::Second", module, module),
+            )
+        } else {
+            let problem = format!("ProblemParser<{}::{}>", module, self.name);
+            (format!("{}, PartOne<_>", problem), format!("{}, PartTwo<_>", problem))
+        };
+        [("One", first), ("Two", second)]
+            .iter()
+            .map(|(part, exercice)| {
+                let arguments = [
+                    self.day.to_string(),
+                    format!("Part::{}", part),
+                    format!("Schooler::<{}>::new()", exercice),
+                ];
+                // Laid out the way rustfmt does, one argument per line past its call width
+                match arguments.join(", ") {
+                    line if line.len() <= 72 => format!("\n        .register({})", line),
+                    _ => format!(
+                        "\n        .register(\n            {},\n        )",
+                        arguments.join(",\n            ")
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    /// Registers both parts at the end of the calendar of the year.
+    fn register(&self, calendar: &str) -> Option<String> {
+        let end = calendar.find(";\n    registry\n}")?;
+        Some(format!("{}{}{}", &calendar[..end], self.entries(), &calendar[end..]))
+    }
+
+    /// The module of a year whose first day this is.
+    fn year_module(&self) -> String {
+        let solver_imports = match self.solvers {
+            true => "",
+            false => "use crate::solver::{PartOne, PartTwo, ProblemParser};\n",
+        };
+        format!(
+            "//! Puzzles of Advent of Code {}.\n\
+             use crate::registry::{{Part, Registry}};\n\
+             use crate::schooler::Schooler;\n\
+             {}\n\
+             pub mod {};\n\n\
+             /// The one place where a new day of the year gets registered.\n\
+             pub fn calendar() -> Registry {{\n    \
+                 let mut registry = Registry::default();\n    \
+                 registry{};\n    \
+                 registry\n\
+             }}\n",
+            self.year,
+            solver_imports,
+            self.module_name(),
+            self.entries()
+        )
+    }
+
+    /// Makes the calendar of the year reachable from the registry.
+    fn add_year(&self, registry: &str) -> Option<String> {
+        let fallback = registry.find("        _ => None,")?;
+        let arm = format!(
+            "        {} => Some(crate::{}::calendar()),\n",
+            self.year,
+            self.year_module_name()
+        );
+        let registry = format!("{}{}{}", &registry[..fallback], arm, &registry[fallback..]);
+
+        let list = "pub const YEARS: &[u16] = &[";
+        let start = registry.find(list)? + list.len();
+        let end = start + registry[start..].find(']')?;
+        let mut years: Vec<u16> = registry[start..end]
+            .split(", ")
+            .filter_map(|year| year.parse().ok())
+            .collect();
+        years.push(self.year);
+        years.sort_unstable();
+        let years = years.iter().map(u16::to_string).collect::<Vec<_>>().join(", ");
+        Some(format!("{}{}{}", &registry[..start], years, &registry[end..]))
+    }
+
+    /// Writes the day in the crate at `root`, returning the files it created or modified.
+    pub fn write(&self, root: &Path) -> Result<Vec<String>, ScaffoldError> {
+        let path = |relative: String| root.join(relative).to_string_lossy().into_owned();
+        let year_dir = path(format!("src/{}", self.year_module_name()));
+        let module_file = path(format!("src/{}/{}.rs", self.year_module_name(), self.module_name()));
+        let module_dir = path(format!("src/{}/{}", self.year_module_name(), self.module_name()));
+        let year_file = path(format!("src/{}/mod.rs", self.year_module_name()));
+        let example_dir = path(format!("inputs/{}", self.year));
+        let example_file = path(format!("inputs/{}/{}.example", self.year, self.day));
+
+        if let Some(existing) = [&module_file, &module_dir]
+            .into_iter()
+            .find(|path| Path::new(path).exists())
+        {
+            return Err(ScaffoldError::AlreadyExists(self.day, existing.clone()));
+        }
+        let read = |file: &String| fs::read_to_string(file).map_err(|e| ScaffoldError::Unreadable(file.clone(), e));
+        let no_anchor = |file: &String| ScaffoldError::NoAnchor(self.day, file.clone());
+        // Files of the crate to rewrite, with their new content
+        let mut edits = vec![];
+        if Path::new(&year_file).exists() {
+            let calendar = read(&year_file)?;
+            if calendar.contains(&format!("mod {};", self.module_name())) {
+                return Err(ScaffoldError::AlreadyExists(self.day, year_file));
+            }
+            let calendar = declare(&calendar, "day_", self.day).ok_or_else(|| no_anchor(&year_file))?;
+            let calendar = self.register(&calendar).ok_or_else(|| no_anchor(&year_file))?;
+            edits.push((year_file, calendar));
+        } else {
+            let (lib_file, registry_file) = (path("src/lib.rs".into()), path("src/registry.rs".into()));
+            let lib = declare(&read(&lib_file)?, "year_", self.year.into()).ok_or_else(|| no_anchor(&lib_file))?;
+            let registry = self
+                .add_year(&read(&registry_file)?)
+                .ok_or_else(|| no_anchor(&registry_file))?;
+            edits.extend([
+                (year_file, self.year_module()),
+                (lib_file, lib),
+                (registry_file, registry),
+            ]);
+        }
+
+        let write = |file: &String, content: &str| {
+            fs::write(file, content).map_err(|e| ScaffoldError::Unwritable(file.clone(), e))
+        };
+        let create_dir = |dir: &String| fs::create_dir_all(dir).map_err(|e| ScaffoldError::Unwritable(dir.clone(), e));
+        create_dir(&year_dir)?;
+        write(&module_file, &self.module())?;
+        let mut written = vec![module_file];
+        // An example already saved is kept
+        if !Path::new(&example_file).exists() {
+            create_dir(&example_dir)?;
+            write(&example_file, "")?;
+            written.push(example_file);
+        }
+        for (file, content) in edits {
+            write(&file, &content)?;
+            written.push(file);
+        }
+        Ok(written)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_declares_days_in_order() {
+        let year = "use x;\n\npub mod day_1;\npub mod day_8;\npub mod day_17;\n\nuse day_8::y;\n";
+        let declared = declare(year, "day_", 9).unwrap();
+        assert_eq!(
+            declared,
+            "use x;\n\npub mod day_1;\npub mod day_8;\npub mod day_9;\npub mod day_17;\n\nuse day_8::y;\n"
+        );
+        let declared = declare(year, "day_", 18).unwrap();
+        assert!(declared.contains("pub mod day_17;\npub mod day_18;\n"));
+        assert_eq!(declare(year, "year_", 2022), None);
+    }
+
+    #[test]
+    fn it_registers_both_parts() {
+        let calendar =
+            "pub fn calendar() -> Registry {\n    registry\n        .register(1, Part::One, x);\n    registry\n}\n";
+        let registered = Scaffold::new(2021, 9, Some("Smoke".into()), false)
+            .register(calendar)
+            .unwrap();
+        assert!(registered.contains(
+            "x)\n        .register(9, Part::One, Schooler::<ProblemParser<day_9::Smoke>, PartOne<_>>::new())"
+        ));
+        assert!(registered.ends_with("PartTwo<_>>::new());\n    registry\n}\n"));
+    }
+
+    #[test]
+    fn it_adds_years_to_the_registry() {
+        let registry = "pub const YEARS: &[u16] = &[2019, 2021];\n\n    match year {\n        2021 => Some(crate::year_2021::calendar()),\n        _ => None,\n    }\n";
+        let added = Scaffold::new(2020, 1, None, true).add_year(registry).unwrap();
+        assert!(added.starts_with("pub const YEARS: &[u16] = &[2019, 2020, 2021];\n"));
+        assert!(added.contains("        2020 => Some(crate::year_2020::calendar()),\n        _ => None,\n"));
+    }
+}
@@ -1,4 +1,8 @@
-use crate::solver::{Exercice, Parse, Solver};
+use std::io::BufRead;
+
+use crate::answer::Answer;
+use crate::param::{Param, Params};
+use crate::solver::{Error, Example, Exercice, Model, Parse, Solver};
 
 pub struct Schooler<P, S>
 where
@@ -24,12 +28,41 @@ where
 
 impl<S, P> Exercice for Schooler<P, S>
 where
-    S: Solver,
-
-    P: Parse<ProblemModel = S::ProblemModel>,
+    S: Solver + Default + Send + Sync + 'static,
+    S::ProblemModel: 'static,
+    P: Parse<ProblemModel = S::ProblemModel> + Default + Send + Sync + 'static,
 {
-    fn solve(&self, lines: &[String]) -> Result<String, crate::solver::Error> {
-        let model = self.parser.parse(lines)?;
-        self.solver.solve(model).map(|sol| sol.to_string()).map_err(Into::into)
+    fn parse(&self, input: &mut dyn BufRead) -> Result<Model, Error> {
+        let model = self.parser.parse(input)?;
+        Ok(Box::new(model))
+    }
+
+    fn solve(&self, model: &Model) -> Result<Answer, Error> {
+        let model = model.downcast_ref::<S::ProblemModel>().ok_or(Error::Unexpected)?;
+        self.solver.solve(model).map(Into::into)
+    }
+
+    fn examples(&self) -> Vec<Example<Answer>> {
+        self.solver
+            .examples()
+            .into_iter()
+            .map(|example| Example {
+                input: example.input,
+                answer: example.answer.into(),
+            })
+            .collect()
+    }
+
+    fn params(&self) -> Vec<Param> {
+        let mut params = self.parser.params();
+        params.extend(self.solver.params());
+        params
+    }
+
+    fn with_params(&self, params: &Params) -> Box<dyn Exercice> {
+        let mut schooler = Self::new();
+        schooler.parser.set_params(params);
+        schooler.solver.set_params(params);
+        schooler
     }
 }
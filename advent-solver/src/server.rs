@@ -0,0 +1,155 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use derive_more::Display;
+
+use crate::config::Config;
+
+#[derive(Debug, Display)]
+pub enum ServerError {
+    #[display(fmt = "No session cookie, set ADVENT_SESSION or `session` in advent.toml")]
+    NoSession,
+    #[display(fmt = "Request to {} failed: {}", _0, _1)]
+    Unreachable(String, String),
+    #[display(fmt = "{} answered {}: {}", _0, _1, _2)]
+    Refused(String, u16, String),
+    #[display(fmt = "Can't write {}: {}", _0, _1)]
+    Unwritable(String, String),
+}
+
+/// Remembers when the server was last requested, to space requests by the rate limit.
+const LAST_REQUEST_FILE: &str = ".last-request";
+
+pub fn unwritable(path: &Path) -> impl Fn(std::io::Error) -> ServerError + '_ {
+    move |e| ServerError::Unwritable(path.display().to_string(), e.to_string())
+}
+
+/// The puzzle server, requested on behalf of the logged in user and at most once per rate limit.
+pub struct Server {
+    config: Config,
+    /// Directory keeping the time of the last request
+    state: PathBuf,
+}
+
+impl Server {
+    pub fn new(config: Config, state: impl Into<PathBuf>) -> Self {
+        Self {
+            config,
+            state: state.into(),
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.config.base_url.trim_end_matches('/'), path)
+    }
+
+    /// Body of the page at `path`.
+    pub fn get(&self, path: &str) -> Result<String, ServerError> {
+        let url = self.url(path);
+        let response = self.request("GET", &url)?.call();
+        self.receive(url, response)
+    }
+
+    /// Body of the page answering `form`, posted to `path`.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ServerError> {
+        let url = self.url(path);
+        let response = self.request("POST", &url)?.send_form(form);
+        self.receive(url, response)
+    }
+
+    /// An authenticated request, once the rate limit allows it.
+    fn request(&self, method: &str, url: &str) -> Result<ureq::Request, ServerError> {
+        let session = self.config.session.as_ref().ok_or(ServerError::NoSession)?;
+        self.wait_for_rate_limit();
+        log::info!("{} {}", method, url);
+        Ok(ureq::request(method, url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", concat!("advent-solver/", env!("CARGO_PKG_VERSION"))))
+    }
+
+    fn receive(&self, url: String, response: Result<ureq::Response, ureq::Error>) -> Result<String, ServerError> {
+        self.record_request()?;
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| ServerError::Unreachable(url, e.to_string())),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                let reason = body.lines().next().unwrap_or_default().trim().to_string();
+                Err(ServerError::Refused(url, status, reason))
+            }
+            Err(e) => Err(ServerError::Unreachable(url, e.to_string())),
+        }
+    }
+
+    fn last_request(&self) -> Option<SystemTime> {
+        let seconds = fs::read_to_string(self.state.join(LAST_REQUEST_FILE)).ok()?;
+        Some(UNIX_EPOCH + Duration::from_secs(seconds.trim().parse().ok()?))
+    }
+
+    fn record_request(&self) -> Result<(), ServerError> {
+        fs::create_dir_all(&self.state).map_err(unwritable(&self.state))?;
+        let file = self.state.join(LAST_REQUEST_FILE);
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        fs::write(&file, now.as_secs().to_string()).map_err(unwritable(&file))
+    }
+
+    fn wait_for_rate_limit(&self) {
+        let rate_limit = Duration::from_secs(self.config.rate_limit);
+        let elapsed = self
+            .last_request()
+            .and_then(|last| SystemTime::now().duration_since(last).ok());
+        if let Some(wait) = elapsed.and_then(|elapsed| rate_limit.checked_sub(elapsed)) {
+            log::warn!(
+                "Waiting {}s before requesting the server again",
+                wait.as_secs_f32().ceil()
+            );
+            thread::sleep(wait);
+        }
+    }
+}
+
+/// A server answering `count` requests with `status` and `body`, handing back the requests it
+/// got, headers and body included.
+#[cfg(test)]
+pub fn stand_in_server(
+    count: usize,
+    status: &'static str,
+    body: &'static str,
+) -> (String, thread::JoinHandle<Vec<String>>) {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        let mut requests = vec![];
+        for stream in listener.incoming().take(count) {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&stream);
+            let headers: Vec<String> = (&mut reader)
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect();
+            let length = headers
+                .iter()
+                .filter_map(|header| header.split_once(": "))
+                .find(|(name, _)| name.eq_ignore_ascii_case("Content-Length"))
+                .map_or(0, |(_, length)| length.parse().unwrap());
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+            requests.push(format!(
+                "{}\n\n{}",
+                headers.join("\n"),
+                String::from_utf8(content).unwrap()
+            ));
+            let response = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\n\r\n{}", status, body.len(), body);
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+        requests
+    });
+    (base_url, server)
+}
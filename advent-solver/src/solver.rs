@@ -1,43 +1,60 @@
+use crate::answer::Answer;
+use crate::diagnostic::Diagnostic;
+use crate::param::{Param, Params};
 use derive_more::Display;
+use std::any::Any;
 use std::fmt::Debug;
-use std::{fmt::Display, marker::PhantomData};
+use std::io::{self, BufRead};
+use std::marker::PhantomData;
+use std::time::Duration;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Display)]
 pub enum Error {
-    UnimplementedSolver,
+    Solving(SolvingError),
+    #[display(fmt = "Empty input")]
     EmptyFile,
-    WrongLine {
-        description: String,
-        line_number: usize,
-        line: String,
-    },
+    WrongLine(Diagnostic),
+    #[display(fmt = "Can't read input: {}", _0)]
+    Unreadable(String),
     ExpectationUnfulfilled(String),
+    #[display(fmt = "Unexpected error")]
     Unexpected,
+    /// Solver panic, with where it happened and its message
+    #[display(fmt = "panicked at {}", _0)]
+    Panicked(String),
+    #[display(fmt = "timed out after {:?}", _0)]
+    TimedOut(Duration),
 }
 
-#[derive(Display, Debug)]
-pub enum FormatError {
-    UnexpectedCharacter,
-    WrongLenght(usize),
-}
+/// A parsed problem model, whose concrete type only the `Exercice` that produced it knows.
+pub type Model = Box<dyn Any>;
 
-pub trait Exercice {
-    fn solve(&self, lines: &[String]) -> Result<String, Error>;
+/// A puzzle example, along with the answer it is expected to give.
+pub struct Example<S> {
+    pub input: &'static str,
+    pub answer: S,
 }
 
-pub struct Schooler<P, S>
-where
-    P: Parse<ProblemModel = S::ProblemModel>,
-    S: Solver,
-{
-    parser: P,
-    solver: S,
+impl<S> Example<S> {
+    pub fn reader(&self) -> &'static [u8] {
+        self.input.as_bytes()
+    }
 }
 
-impl<S: Solver, P: Parse<ProblemModel = S::ProblemModel>> Exercice for Schooler<P, S> {
-    fn solve(&self, lines: &[String]) -> Result<String, Error> {
-        let model = self.parser.parse(lines)?;
-        self.solver.solve(model).map(|sol| sol.to_string())
+/// A runnable part of a day, shareable between threads so that batch runs can isolate it.
+pub trait Exercice: Send + Sync {
+    fn parse(&self, input: &mut dyn BufRead) -> Result<Model, Error>;
+    fn solve(&self, model: &Model) -> Result<Answer, Error>;
+    fn examples(&self) -> Vec<Example<Answer>>;
+    /// Parameters of the parser and of the solver.
+    fn params(&self) -> Vec<Param>;
+    /// A copy of the exercice taking `params` in place of the defaults.
+    fn with_params(&self, params: &Params) -> Box<dyn Exercice>;
+
+    /// A copy of the exercice set up for the puzzle examples, its parameters taking their
+    /// example values.
+    fn for_examples(&self) -> Box<dyn Exercice> {
+        self.with_params(&Params::default().for_examples(&self.params()))
     }
 }
 
@@ -49,49 +66,183 @@ pub struct Unimplemented<P, S> {
 impl<P, S> Default for Unimplemented<P, S> {
     fn default() -> Self {
         Self {
-            p: PhantomData::<P>::default(),
-            s: PhantomData::<S>::default(),
+            p: PhantomData,
+            s: PhantomData,
         }
     }
 }
 
 pub trait Parse {
     type ProblemModel;
-    fn parse(&self, lines: &[String]) -> Result<Self::ProblemModel, crate::solver::Error>;
+    /// Parses the input as it is read, line by line with `BufRead::lines` usually.
+    fn parse(&self, input: &mut dyn BufRead) -> Result<Self::ProblemModel, crate::solver::Error>;
+
+    /// Puzzle constants `--param` can change, none by default.
+    fn params(&self) -> Vec<Param> {
+        vec![]
+    }
+
+    /// Takes the values of `params`, already checked against the declared ones.
+    fn set_params(&mut self, _params: &Params) {}
 }
 
 pub trait Solver {
     type ProblemModel;
-    type Solution: ToString;
-    fn solve(&self, model: Self::ProblemModel) -> Result<Self::Solution, Error>;
+    type Solution: Into<Answer>;
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, Error>;
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        vec![]
+    }
+
+    /// Puzzle constants `--param` can change, none by default.
+    fn params(&self) -> Vec<Param> {
+        vec![]
+    }
+
+    /// Takes the values of `params`, already checked against the declared ones.
+    fn set_params(&mut self, _params: &Params) {}
 }
 
 impl<P, S> Solver for Unimplemented<P, S>
 where
     P: Debug,
-    S: Display,
+    S: Into<Answer>,
 {
     type ProblemModel = P;
 
     type Solution = S;
 
-    fn solve(&self, _model: Self::ProblemModel) -> Result<Self::Solution, Error> {
-        Err(Error::UnimplementedSolver)
+    fn solve(&self, _model: &Self::ProblemModel) -> Result<Self::Solution, Error> {
+        Err(SolvingError::Unimplemented.into())
     }
 }
 
 #[derive(Debug)]
 pub enum ParsingError {
-    IncorrectLine {
-        description: String,
-        number: usize,
-        line: String,
-    },
+    EmptyInput,
+    IncorrectLine(Diagnostic),
+    Unreadable(io::Error),
+}
+
+impl From<io::Error> for ParsingError {
+    fn from(e: io::Error) -> Self {
+        ParsingError::Unreadable(e)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Unreadable(e.to_string())
+    }
+}
+
+/// Why a well-formed input still yields no answer.
+#[derive(Debug, Clone, Display)]
+pub enum SolvingError {
+    #[display(fmt = "No solution: {}", _0)]
+    NoSolution(String),
+    #[display(fmt = "Ambiguous input: {}", _0)]
+    Ambiguous(String),
+    #[display(fmt = "Overflow while computing {}", _0)]
+    Overflow(String),
+    #[display(fmt = "Part not implemented yet")]
+    Unimplemented,
+}
+
+impl From<ParsingError> for Error {
+    fn from(e: ParsingError) -> Self {
+        match e {
+            ParsingError::EmptyInput => Error::EmptyFile,
+            ParsingError::IncorrectLine(diagnostic) => Error::WrongLine(diagnostic),
+            ParsingError::Unreadable(e) => e.into(),
+        }
+    }
+}
+
+impl From<SolvingError> for Error {
+    fn from(e: SolvingError) -> Self {
+        Error::Solving(e)
+    }
 }
-pub enum SolvingError {}
 
 pub trait Problem: Sized {
-    fn parse(lines: &[String]) -> Result<Self, ParsingError>;
-    fn part_one(&self) -> Result<usize, SolvingError>;
-    fn part_two(&self) -> Result<usize, SolvingError>;
+    fn parse(input: &mut dyn BufRead) -> Result<Self, ParsingError>;
+    fn part_one(&self) -> Result<Answer, SolvingError>;
+    fn part_two(&self) -> Result<Answer, SolvingError>;
+
+    fn examples_part_one() -> Vec<Example<Answer>> {
+        vec![]
+    }
+
+    fn examples_part_two() -> Vec<Example<Answer>> {
+        vec![]
+    }
+}
+
+/// Parses a `Problem` as any other `Parse`, so it can be paired with a `Solver` by a `Schooler`.
+pub struct ProblemParser<T> {
+    problem: PhantomData<T>,
+}
+
+impl<T> Default for ProblemParser<T> {
+    fn default() -> Self {
+        Self { problem: PhantomData }
+    }
+}
+
+impl<T: Problem> Parse for ProblemParser<T> {
+    type ProblemModel = T;
+
+    fn parse(&self, input: &mut dyn BufRead) -> Result<Self::ProblemModel, Error> {
+        T::parse(input).map_err(Into::into)
+    }
+}
+
+/// Solves the first part of a `Problem`.
+pub struct PartOne<T> {
+    problem: PhantomData<T>,
+}
+
+impl<T> Default for PartOne<T> {
+    fn default() -> Self {
+        Self { problem: PhantomData }
+    }
+}
+
+impl<T: Problem> Solver for PartOne<T> {
+    type ProblemModel = T;
+    type Solution = Answer;
+
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, Error> {
+        model.part_one().map_err(Into::into)
+    }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        T::examples_part_one()
+    }
+}
+
+/// Solves the second part of a `Problem`.
+pub struct PartTwo<T> {
+    problem: PhantomData<T>,
+}
+
+impl<T> Default for PartTwo<T> {
+    fn default() -> Self {
+        Self { problem: PhantomData }
+    }
+}
+
+impl<T: Problem> Solver for PartTwo<T> {
+    type ProblemModel = T;
+    type Solution = Answer;
+
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, Error> {
+        model.part_two().map_err(Into::into)
+    }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        T::examples_part_two()
+    }
 }
@@ -0,0 +1,138 @@
+use derive_more::Display;
+
+use crate::answer::Answer;
+use crate::answers::Rejection;
+use crate::registry::Part;
+use crate::server::{Server, ServerError};
+
+/// What the puzzle server made of an answer.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Rejected(Rejection),
+    /// Answered too recently, with how long to wait when the server tells
+    RateLimited(Option<String>),
+    /// The part is already solved, or still locked
+    WrongLevel,
+}
+
+/// The text of the `<article>` of `page`, tags left out.
+fn article(page: &str) -> String {
+    let start = page.find("<article").unwrap_or(0);
+    let end = page[start..].find("</article>").map_or(page.len(), |end| start + end);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in page[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads the verdict out of the page answering a submission, `Err` holding the text of a page
+/// saying nothing known.
+pub fn verdict(page: &str) -> Result<Verdict, String> {
+    let text = article(page);
+    if text.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if text.contains("That's not the right answer") {
+        Ok(Verdict::Rejected(if text.contains("too high") {
+            Rejection::TooHigh
+        } else if text.contains("too low") {
+            Rejection::TooLow
+        } else {
+            Rejection::Wrong
+        }))
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| wait.to_string());
+        Ok(Verdict::RateLimited(wait))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::WrongLevel)
+    } else {
+        Err(text)
+    }
+}
+
+#[derive(Debug, Display)]
+pub enum SubmitError {
+    #[display(fmt = "{}", _0)]
+    Server(ServerError),
+    #[display(fmt = "Unexpected answer from the server: {}", _0)]
+    Unrecognized(String),
+}
+
+/// Posts `answer` to `part` of the puzzle of `day`.
+pub fn submit(server: &Server, year: u16, day: usize, part: Part, answer: &Answer) -> Result<Verdict, SubmitError> {
+    let (level, answer) = (part.to_string(), answer.to_string());
+    let page = server
+        .post(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &level), ("answer", &answer)],
+        )
+        .map_err(SubmitError::Server)?;
+    verdict(&page).map_err(SubmitError::Unrecognized)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::Config;
+    use crate::server::stand_in_server;
+
+    #[test]
+    fn it_reads_verdicts() {
+        let page = |text: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", text);
+        assert_eq!(
+            verdict(&page("That's the right answer! You are <em>one gold star</em> closer.")),
+            Ok(Verdict::Correct)
+        );
+        assert_eq!(
+            verdict(&page("That's not the right answer; your answer is too high.")),
+            Ok(Verdict::Rejected(Rejection::TooHigh))
+        );
+        assert_eq!(
+            verdict(&page("That's not the right answer. If you're stuck, ...")),
+            Ok(Verdict::Rejected(Rejection::Wrong))
+        );
+        assert_eq!(
+            verdict(&page("You gave an answer too recently. You have 4m 12s left to wait.")),
+            Ok(Verdict::RateLimited(Some("4m 12s".into())))
+        );
+        assert_eq!(verdict("<p>Nothing</p>"), Err("Nothing".into()));
+    }
+
+    #[test]
+    fn it_posts_the_answer_of_a_level() {
+        let (base_url, server) = stand_in_server(
+            1,
+            "200 OK",
+            "<article>That's not the right answer; your answer is too low.</article>",
+        );
+        let state = std::env::temp_dir().join(format!("advent-solver-submit-{}", std::process::id()));
+        let config = Config {
+            session: Some("c0ffee".into()),
+            base_url,
+            rate_limit: 0,
+        };
+
+        let verdict = submit(
+            &Server::new(config, &state),
+            2021,
+            6,
+            Part::Two,
+            &Answer::Integer(26984457539),
+        );
+        assert_eq!(verdict.unwrap(), Verdict::Rejected(Rejection::TooLow));
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2021/day/6/answer HTTP/1.1"));
+        assert!(requests[0].ends_with("\n\nlevel=2&answer=26984457539"));
+        std::fs::remove_dir_all(state).unwrap();
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+use crate::combinator::{diagnose, number};
+use crate::param::{Param, Params};
+use crate::solver::{self, Example, Parse, Solver};
+
+const EXAMPLE: &str = "\
+199
+200
+208
+210
+200
+207
+240
+269
+260
+263";
+
+const WINDOW: Param = Param {
+    name: "window",
+    help: "Depths summed by each sliding window of part 2",
+    default: 3,
+    min: 1,
+    max: None,
+    example: None,
+};
+
+/// Increases along the depth sweep, counted as the depths are read.
+#[derive(Debug, Default)]
+pub struct Sweep {
+    /// Depths deeper than the one before them
+    increases: usize,
+    /// Sums of sliding windows larger than the one before them
+    window_increases: usize,
+}
+
+pub struct Parser {
+    window: usize,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self { window: WINDOW.default }
+    }
+}
+
+impl Parse for Parser {
+    type ProblemModel = Sweep;
+
+    fn parse(&self, input: &mut dyn BufRead) -> Result<Self::ProblemModel, crate::solver::Error> {
+        let mut sweep = Sweep::default();
+        // Two windows in a row share all but their first and last depths, so comparing those
+        // tells which sum is the largest: only the depths of the last window are kept
+        let mut window = VecDeque::new();
+        for (n, line) in input.lines().enumerate() {
+            let line = line?;
+            let depth: usize = diagnose(n, &line, number()).map_err(solver::Error::WrongLine)?;
+            if window.back().is_some_and(|previous| depth > *previous) {
+                sweep.increases += 1;
+            }
+            if window.len() == self.window && window.pop_front().is_some_and(|first| depth > first) {
+                sweep.window_increases += 1;
+            }
+            window.push_back(depth);
+        }
+        Ok(sweep)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![WINDOW]
+    }
+
+    fn set_params(&mut self, params: &Params) {
+        self.window = params.get(&WINDOW);
+    }
+}
+
+#[derive(Default)]
+pub struct First {}
+
+impl Solver for First {
+    type ProblemModel = Sweep;
+    type Solution = usize;
+
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, crate::solver::Error> {
+        Ok(model.increases)
+    }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 7,
+        }]
+    }
+}
+
+#[derive(Default)]
+pub struct Second {}
+
+impl Solver for Second {
+    type ProblemModel = Sweep;
+    type Solution = usize;
+
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, crate::solver::Error> {
+        Ok(model.window_increases)
+    }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 5,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_sums_windows_of_any_size() {
+        let sweep = |window| Parser { window }.parse(&mut EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(sweep(1).window_increases, sweep(1).increases);
+        assert_eq!(sweep(2).window_increases, 5);
+        assert_eq!(sweep(10).window_increases, 0);
+    }
+}
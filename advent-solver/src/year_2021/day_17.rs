@@ -0,0 +1,116 @@
+use crate::answer::Answer;
+use crate::solver::SolvingError;
+use crate::solver::{Example, ParsingError, Problem};
+use advent_derive::FromLine;
+use std::io::BufRead;
+use std::str::FromStr;
+
+const EXAMPLE: &str = "target area: x=20..30, y=-10..-5";
+
+/// A bound of the target area, which lies below the launch point.
+#[derive(Debug, Clone, Copy)]
+pub struct Below(i64);
+
+impl FromStr for Below {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse() {
+            Ok(y) if y < 0 => Ok(Below(y)),
+            Ok(_) => Err("Expected a negative y bound".into()),
+            Err(e) => Err(format!("Not a number: {}", e)),
+        }
+    }
+}
+
+#[derive(Debug, FromLine)]
+#[pattern("target area: x={target_min_x}..{target_max_x}, y={target_min_y}..{target_max_y}")]
+pub struct ProbeSystem {
+    target_min_x: usize,
+    target_max_x: usize,
+    target_min_y: Below,
+    target_max_y: Below,
+}
+
+impl ProbeSystem {
+    fn is_in(&self, x: usize, y: i64) -> bool {
+        self.target_max_x >= x && x >= self.target_min_x && self.target_max_y.0 >= y && y >= self.target_min_y.0
+    }
+
+    fn hits(&self, mut vx: usize, mut vy: i64) -> bool {
+        let (mut x, mut y) = (0, 0i64);
+        while x <= self.target_max_x && y >= self.target_min_y.0 {
+            if self.is_in(x, y) {
+                return true;
+            }
+            x += vx;
+            y += vy;
+            vx = vx.saturating_sub(1);
+            vy -= 1;
+        }
+        false
+    }
+}
+
+impl Problem for ProbeSystem {
+    // target area: x=117..164, y=-140..-89
+    fn parse(input: &mut dyn BufRead) -> Result<Self, ParsingError> {
+        let line = input.lines().next().transpose()?.ok_or(ParsingError::EmptyInput)?;
+        ProbeSystem::from_str(&line).map_err(|e| ParsingError::IncorrectLine(e.into_diagnostic(0, &line)))
+    }
+
+    fn part_one(&self) -> Result<Answer, SolvingError> {
+        let max_velocity = self.target_min_y.0.unsigned_abs();
+        let max_high = max_velocity * (max_velocity + 1) / 2;
+        Ok((max_high - max_velocity).into())
+    }
+
+    fn part_two(&self) -> Result<Answer, SolvingError> {
+        let min_speed_x = (2.0 * self.target_min_x as f64 + 0.5 * 0.5).sqrt() - 0.5;
+        let min_speed_x = min_speed_x.ceil() as usize;
+        let max_speed_x = self.target_max_x;
+
+        let min_speed_y = self.target_min_y.0;
+        let max_speed_y = -self.target_min_y.0;
+
+        let mut count = 0;
+        for vx in min_speed_x..=max_speed_x {
+            for vy in min_speed_y..=max_speed_y {
+                if self.hits(vx, vy) {
+                    count += 1;
+                }
+            }
+        }
+        Ok(count.into())
+    }
+
+    fn examples_part_one() -> Vec<Example<Answer>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 45.into(),
+        }]
+    }
+
+    fn examples_part_two() -> Vec<Example<Answer>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 112.into(),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_expects_the_target_below() {
+        let error = ProbeSystem::from_str("target area: x=20..30, y=-10..5").unwrap_err();
+        assert_eq!(
+            (error.column, error.error.as_str()),
+            (30, "Expected a negative y bound")
+        );
+        let probe = ProbeSystem::from_str(EXAMPLE).unwrap();
+        assert_eq!((probe.target_min_x, probe.target_max_y.0), (20, -5));
+    }
+}
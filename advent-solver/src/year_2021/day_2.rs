@@ -0,0 +1,145 @@
+use std::io::BufRead;
+use std::str::FromStr;
+
+use advent_derive::FromLine;
+
+use crate::solver::{self, Example, Parse, SolvingError};
+
+#[derive(Clone, Copy, FromLine)]
+pub enum Move {
+    #[pattern("forward {}")]
+    Forward(usize),
+    #[pattern("down {}")]
+    Down(usize),
+    #[pattern("up {}")]
+    Up(usize),
+}
+
+const EXAMPLE: &str = "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2";
+
+#[derive(Default)]
+pub struct Parser {}
+impl Parse for Parser {
+    type ProblemModel = Vec<Move>;
+
+    fn parse(&self, input: &mut dyn BufRead) -> Result<Self::ProblemModel, crate::solver::Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_number, line)| {
+                let line = line?;
+                Move::from_str(&line).map_err(|e| solver::Error::WrongLine(e.into_diagnostic(line_number, &line)))
+            })
+            .collect::<Result<Vec<_>, _>>()
+    }
+}
+
+#[derive(Default)]
+pub struct First {}
+impl solver::Solver for First {
+    type ProblemModel = Vec<Move>;
+
+    type Solution = usize;
+
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, solver::Error> {
+        Ok(part_1(model)?)
+    }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 150,
+        }]
+    }
+}
+
+fn overflow(what: &str) -> SolvingError {
+    SolvingError::Overflow(what.into())
+}
+
+/// The submarine can't go any higher than the surface, where the depth is 0.
+fn above_surface() -> SolvingError {
+    overflow("the depth, the submarine rising above the surface")
+}
+
+pub fn part_1(moves: &[Move]) -> Result<usize, SolvingError> {
+    let (mut x, mut depth) = (0usize, 0usize);
+    for &a_move in moves {
+        match a_move {
+            Move::Forward(amp) => {
+                x = x.checked_add(amp).ok_or_else(|| overflow("the position"))?;
+            }
+            Move::Down(amp) => depth = depth.checked_add(amp).ok_or_else(|| overflow("the depth"))?,
+            Move::Up(amp) => depth = depth.checked_sub(amp).ok_or_else(above_surface)?,
+        }
+    }
+    x.checked_mul(depth).ok_or_else(|| overflow("the answer"))
+}
+
+#[derive(Default)]
+pub struct Second {}
+impl solver::Solver for Second {
+    type ProblemModel = Vec<Move>;
+
+    type Solution = usize;
+
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, solver::Error> {
+        Ok(part_2(model)?)
+    }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 900,
+        }]
+    }
+}
+
+pub fn part_2(moves: &[Move]) -> Result<usize, SolvingError> {
+    let signed = |amp: usize| i64::try_from(amp).ok();
+    let (mut x, mut aim, mut depth) = (0usize, 0i64, 0i64);
+    for &a_move in moves {
+        match a_move {
+            Move::Forward(amp) => {
+                x = x.checked_add(amp).ok_or_else(|| overflow("the position"))?;
+                depth = signed(amp)
+                    .and_then(|amp| aim.checked_mul(amp))
+                    .and_then(|dive| depth.checked_add(dive))
+                    .ok_or_else(|| overflow("the depth"))?;
+            }
+            Move::Down(amp) => {
+                aim = signed(amp)
+                    .and_then(|amp| aim.checked_add(amp))
+                    .ok_or_else(|| overflow("the aim"))?;
+            }
+            Move::Up(amp) => {
+                aim = signed(amp)
+                    .and_then(|amp| aim.checked_sub(amp))
+                    .ok_or_else(|| overflow("the aim"))?;
+            }
+        }
+    }
+    let depth = usize::try_from(depth).map_err(|_| above_surface())?;
+    x.checked_mul(depth).ok_or_else(|| overflow("the answer"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_reports_moves_above_the_surface() {
+        let moves = [Move::Forward(5), Move::Down(2), Move::Up(3)];
+        assert!(matches!(part_1(&moves), Err(SolvingError::Overflow(_))));
+        let moves = [Move::Up(3), Move::Forward(5)];
+        assert!(matches!(part_2(&moves), Err(SolvingError::Overflow(_))));
+        let moves = [Move::Forward(usize::MAX), Move::Down(2)];
+        assert!(matches!(part_1(&moves), Err(SolvingError::Overflow(_))));
+    }
+}
@@ -0,0 +1,272 @@
+use std::io::BufRead;
+
+use crate::combinator::{diagnose, row};
+use crate::diagnostic::Diagnostic;
+use crate::param::{Param, Params};
+use crate::solver::{self, Example, Solver, SolvingError};
+
+/// The numbers of the example have 5 bits, those of the puzzle inputs 12
+const EXAMPLE: &str = "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010";
+
+const BITS: Param = Param {
+    name: "bits",
+    help: "Bits of each number of the report",
+    default: 12,
+    min: 1,
+    // The answer multiplies two of these numbers
+    max: Some(32),
+    example: Some(5),
+};
+
+#[derive(Debug)]
+pub struct BinSeq(Vec<bool>);
+
+impl BinSeq {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn as_number(&self) -> usize {
+        //
+        let mut value = 0;
+        for i in 0..self.len() {
+            if self.0[self.len() - 1 - i] {
+                value += 1 << i;
+            }
+        }
+        value
+    }
+
+    fn matches(&self, pattern: &[bool]) -> bool {
+        pattern.len() <= self.len() && pattern.iter().enumerate().all(|(i, bit)| self.0[i] == *bit)
+    }
+}
+
+impl std::ops::Not for BinSeq {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self(self.0.into_iter().map(std::ops::Not::not).collect())
+    }
+}
+
+fn bit(c: char) -> Option<bool> {
+    match c {
+        '0' => Some(false),
+        '1' => Some(true),
+        _ => None,
+    }
+}
+
+struct OccSeq(Vec<i64>);
+
+impl OccSeq {
+    fn new(len: usize) -> Self {
+        Self(vec![0; len])
+    }
+
+    fn with_account(&self, bin_seq: &BinSeq) -> Self {
+        Self(
+            self.0
+                .iter()
+                .zip(&bin_seq.0)
+                .map(|(c, y)| {
+                    c + match y {
+                        false => -1,
+                        true => 1,
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    fn as_most_common(&self) -> Result<BinSeq, solver::Error> {
+        let bin_seq = self
+            .0
+            .iter()
+            .map(|x| match *x {
+                x if x < 0 => Ok(false),
+                0 => Err(SolvingError::Ambiguous("no most common bit when required".into()).into()),
+                _ => Ok(true),
+            })
+            .collect::<Result<Vec<_>, solver::Error>>()?;
+
+        Ok(BinSeq(bin_seq))
+    }
+}
+
+pub struct Parser {
+    bits: usize,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self { bits: BITS.default }
+    }
+}
+
+impl crate::solver::Parse for Parser {
+    type ProblemModel = Vec<BinSeq>;
+
+    fn parse(&self, input: &mut dyn BufRead) -> Result<Self::ProblemModel, crate::solver::Error> {
+        let row = row(bit, "'0' or '1'");
+        let mut report = vec![];
+        for (line_index, line) in input.lines().enumerate() {
+            let line = line?;
+            let bits = diagnose(line_index, &line, &row).map_err(solver::Error::WrongLine)?;
+            if bits.len() != self.bits {
+                let message = format!("Expected {} bits per line, found {}", self.bits, bits.len());
+                return Err(solver::Error::WrongLine(Diagnostic::new(line_index, &line, message)));
+            }
+            report.push(BinSeq(bits));
+        }
+        Ok(report)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![BITS]
+    }
+
+    fn set_params(&mut self, params: &Params) {
+        self.bits = params.get(&BITS);
+    }
+}
+
+#[derive(Default)]
+pub struct First {}
+
+impl Solver for First {
+    type ProblemModel = Vec<BinSeq>;
+    type Solution = usize;
+
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, solver::Error> {
+        first_part(model)
+    }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 198,
+        }]
+    }
+}
+
+fn first_part(diagnostic: &[BinSeq]) -> Result<usize, solver::Error> {
+    let len = diagnostic.first().map_or(0, BinSeq::len);
+    let most_common = diagnostic
+        .iter()
+        .fold(OccSeq::new(len), |acc, number| acc.with_account(number))
+        .as_most_common()?;
+
+    let gamma = most_common.as_number();
+    let epsilon = (!most_common).as_number();
+    log::debug!("gamma: {}, epsilon: {}", gamma, epsilon);
+
+    Ok(gamma * epsilon)
+}
+
+#[derive(Default)]
+pub struct Second {}
+
+impl Solver for Second {
+    type ProblemModel = Vec<BinSeq>;
+
+    type Solution = usize;
+
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, solver::Error> {
+        let oxygen = most_common_in_matching(model, MostCommon {}, vec![])?;
+        let co2 = most_common_in_matching(model, LeastCommon {}, vec![])?;
+        Ok(oxygen.as_number() * co2.as_number())
+    }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 230,
+        }]
+    }
+}
+
+fn bool_to_value(b: bool) -> i64 {
+    match b {
+        true => 1,
+        false => -1,
+    }
+}
+
+trait BitCriteria {
+    fn keep(&self, count: i64) -> bool;
+}
+
+struct MostCommon {}
+impl BitCriteria for MostCommon {
+    fn keep(&self, count: i64) -> bool {
+        count >= 0
+    }
+}
+
+struct LeastCommon {}
+impl BitCriteria for LeastCommon {
+    fn keep(&self, count: i64) -> bool {
+        count < 0
+    }
+}
+
+fn most_common_in_matching(
+    diagnostic: &[BinSeq],
+    criteria: impl BitCriteria,
+    mut pattern: Vec<bool>,
+) -> Result<&BinSeq, solver::Error> {
+    let count = diagnostic.iter().filter(|b| b.matches(&pattern)).count();
+
+    if count == 1 {
+        return Ok(diagnostic.iter().find(|b| b.matches(&pattern)).unwrap());
+    }
+    if diagnostic.first().is_none_or(|b| pattern.len() == b.len()) {
+        return Err(SolvingError::Ambiguous("duplicated binary sequence found".into()).into());
+    }
+
+    let signed_count: i64 = diagnostic
+        .iter()
+        .filter(|b| b.matches(&pattern))
+        .inspect(|b| log::trace!("{:?} matches {:?}", b, pattern))
+        .map(|b| b.0[pattern.len()])
+        .map(bool_to_value)
+        .sum();
+
+    pattern.push(criteria.keep(signed_count));
+
+    most_common_in_matching(diagnostic, criteria, pattern)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solver::Parse;
+
+    #[test]
+    fn it_matches_correctly() {
+        let inner = [
+            true, true, false, false, true, true, false, false, true, true, false, false,
+        ];
+        let bin_seq = BinSeq(inner.to_vec());
+        let matcher = [true, true];
+        let not_matcher = [false, true];
+        assert!(bin_seq.matches(&matcher));
+        assert!(!bin_seq.matches(&not_matcher));
+    }
+
+    #[test]
+    fn it_reads_reports_of_any_width() {
+        let diagnostic = Parser { bits: 5 }.parse(&mut EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(First {}.solve(&diagnostic).unwrap(), 198);
+        assert_eq!(Second {}.solve(&diagnostic).unwrap(), 230);
+        match Parser::default().parse(&mut EXAMPLE.as_bytes()) {
+            Err(solver::Error::WrongLine(diagnostic)) => {
+                assert_eq!(diagnostic.line_number, 1);
+                assert_eq!(diagnostic.message, "Expected 12 bits per line, found 5");
+            }
+            outcome => panic!("{:?}", outcome.map(|_| ())),
+        }
+    }
+}
@@ -0,0 +1,367 @@
+use crate::combinator::{diagnose, exactly, literal, map, number, preceded, separated, space, spaces, terminated};
+use crate::diagnostic::Diagnostic;
+use crate::param::{Param, Params};
+use crate::solver::{self, Example, Parse, Solver, SolvingError};
+
+use ansi_term::Style;
+use derive_more::Display;
+
+use ansi_term::ANSIString;
+use std::collections::LinkedList;
+use std::fmt::Display;
+use std::io::{self, BufRead};
+
+//
+
+#[derive(Debug, Clone)]
+struct Element {
+    number: usize,
+    played: bool,
+}
+
+impl Element {
+    fn new(number: usize) -> Self {
+        Self { number, played: false }
+    }
+
+    fn play(&mut self) {
+        self.played = true;
+    }
+
+    fn to_string(&self) -> ANSIString<'static> {
+        if self.played {
+            Style::new().bold().paint(self.number.to_string())
+        } else {
+            self.number.to_string().into()
+        }
+    }
+}
+
+impl AsRef<usize> for Element {
+    fn as_ref(&self) -> &usize {
+        &self.number
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Board {
+    /// As many rows as columns
+    grid: Vec<Vec<Element>>,
+}
+
+impl Board {
+    fn play(&mut self, number: usize) {
+        self.grid.iter_mut().for_each(|line| {
+            line.iter_mut().for_each(|el| {
+                if el.number == number {
+                    el.play();
+                }
+            })
+        })
+    }
+
+    fn wins(&self) -> Option<usize> {
+        if self.grid.iter().any(|line| line.iter().all(|el| el.played))
+            || (0..self.grid.len()).any(|col| self.grid.iter().all(|line| line[col].played))
+        {
+            let as_point = |el: &Element| -> usize {
+                if el.played {
+                    0
+                } else {
+                    el.number
+                }
+            };
+
+            let sum: usize = self
+                .grid
+                .iter()
+                .map(|line| line.iter().map(as_point).sum::<usize>())
+                .sum();
+            Some(sum)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for line in &self.grid {
+            for el in line {
+                write!(f, "{:>5}  ", el.to_string())?
+            }
+            writeln!(f)?
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Display)]
+pub enum BoardParsingError {
+    WrongLine(Diagnostic),
+    #[display(fmt = "Can't read input: {}", _0)]
+    Unreadable(io::Error),
+    #[display(fmt = "Unexpected end of input, a board is missing lines")]
+    UnexpectedEndOfInput,
+}
+
+impl From<BoardParsingError> for solver::Error {
+    fn from(e: BoardParsingError) -> Self {
+        match e {
+            BoardParsingError::WrongLine(diagnostic) => solver::Error::WrongLine(diagnostic),
+            BoardParsingError::Unreadable(e) => e.into(),
+            e => solver::Error::ExpectationUnfulfilled(e.to_string()),
+        }
+    }
+}
+
+impl Board {
+    /// Reads `size` rows of `size` numbers.
+    pub fn parse(
+        lines: &mut impl Iterator<Item = (usize, io::Result<String>)>,
+        size: usize,
+    ) -> Result<Self, BoardParsingError> {
+        let row = preceded(
+            spaces(),
+            terminated(exactly(size, map(number(), Element::new), space()), spaces()),
+        );
+        let mut grid = vec![];
+        for _ in 0..size {
+            let (line_index, line) = lines.next().ok_or(BoardParsingError::UnexpectedEndOfInput)?;
+            let line = line.map_err(BoardParsingError::Unreadable)?;
+            grid.push(diagnose(line_index, &line, &row).map_err(BoardParsingError::WrongLine)?);
+        }
+        Ok(Board { grid })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Bingo {
+    numbers: LinkedList<usize>,
+    boards: Vec<Board>,
+}
+
+pub enum Status {
+    Finished,
+    Playing(Vec<usize>),
+}
+
+impl Status {
+    fn into_win(self) -> Option<Vec<usize>> {
+        use Status::Playing;
+        match self {
+            Playing(win) if win.is_empty() => None,
+            Playing(win) => Some(win),
+            _ => None,
+        }
+    }
+}
+
+impl Bingo {
+    fn playable_boards(&mut self) -> impl Iterator<Item = &mut Board> {
+        self.boards.iter_mut().filter(|b| b.wins().is_none())
+    }
+    fn play_next_number(&mut self) -> Status {
+        if let Some(number) = self.numbers.pop_front() {
+            let wins = self
+                .playable_boards()
+                .filter_map(|b| {
+                    b.play(number);
+                    let win = b.wins();
+                    if let Some(points) = win {
+                        log::debug!("Board wins at {} * {}\n{}", number, points, b);
+                    }
+                    win
+                })
+                .map(|points| number * points)
+                .collect();
+            Status::Playing(wins)
+        } else {
+            Status::Finished
+        }
+    }
+}
+
+impl Iterator for Bingo {
+    type Item = Status;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.play_next_number() {
+            Status::Finished => None,
+            s => Some(s),
+        }
+    }
+}
+
+const EXAMPLE: &str = "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7";
+
+const SIZE: Param = Param {
+    name: "size",
+    help: "Rows and columns of each board",
+    default: 5,
+    min: 1,
+    max: None,
+    example: None,
+};
+
+pub struct Parser {
+    size: usize,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self { size: SIZE.default }
+    }
+}
+
+impl Parse for Parser {
+    type ProblemModel = Bingo;
+
+    fn parse(&self, input: &mut dyn BufRead) -> Result<Self::ProblemModel, crate::solver::Error> {
+        let mut lines = input.lines().enumerate();
+        let first = lines
+            .next()
+            .map(|(_, line)| line)
+            .transpose()?
+            .ok_or(solver::Error::EmptyFile)?;
+        let numbers = diagnose(0, &first, separated(number(), literal(",")))
+            .map_err(solver::Error::WrongLine)?
+            .into_iter()
+            .collect();
+
+        let mut boards = vec![];
+        while let Some((line_index, separator)) = lines.next() {
+            let separator = separator?;
+            if !separator.trim().is_empty() {
+                let diagnostic = Diagnostic::new(line_index, &separator, "Expected a blank line before the board");
+                return Err(solver::Error::WrongLine(diagnostic));
+            }
+            boards.push(Board::parse(&mut lines, self.size)?)
+        }
+        if boards.is_empty() {
+            return Err(solver::Error::ExpectationUnfulfilled(
+                "No board after the drawn numbers".into(),
+            ));
+        }
+
+        Ok(Bingo { numbers, boards })
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![SIZE]
+    }
+
+    fn set_params(&mut self, params: &Params) {
+        self.size = params.get(&SIZE);
+    }
+}
+
+fn no_winner() -> solver::Error {
+    SolvingError::NoSolution("no board ever wins".into()).into()
+}
+
+#[derive(Default)]
+pub struct First {}
+
+impl Solver for First {
+    type ProblemModel = Bingo;
+
+    type Solution = usize;
+
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, solver::Error> {
+        model
+            .clone()
+            .find_map(|s| s.into_win())
+            .map(|win_vec| *win_vec.first().unwrap())
+            .ok_or_else(no_winner)
+    }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 4512,
+        }]
+    }
+}
+
+#[derive(Default)]
+pub struct Second {}
+
+impl Solver for Second {
+    type ProblemModel = Bingo;
+
+    type Solution = usize;
+
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, solver::Error> {
+        let plays = model.clone().filter_map(|s| s.into_win()).collect::<Vec<_>>();
+        log::trace!("Winning scores, number after number: {:?}", plays);
+        plays
+            .last()
+            .map(|win_vec| *win_vec.last().unwrap())
+            .ok_or_else(no_winner)
+    }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 1924,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn it_plays_element() {
+        let mut grid = vec![vec![Element::new(0); 5]; 5];
+
+        grid[1][3].number = 4;
+
+        let mut board = Board { grid };
+        println!("{}", board);
+        board.play(4);
+        println!("{}", board);
+    }
+
+    #[test]
+    fn it_plays_boards_of_any_size() {
+        let bingo = "1,2,3\n\n1 2 3\n4 5 6\n7 8 9";
+        let model = Parser { size: 3 }.parse(&mut bingo.as_bytes()).unwrap();
+        assert_eq!(First {}.solve(&model).unwrap(), 117);
+        assert!(matches!(
+            Parser::default().parse(&mut bingo.as_bytes()),
+            Err(solver::Error::WrongLine(_))
+        ));
+    }
+
+    #[test]
+    fn it_wants_blank_lines_between_boards() {
+        let bingo = "1,2\n\n1 2\n3 4\n5 6\n7 8";
+        match (Parser { size: 2 }.parse(&mut bingo.as_bytes())) {
+            Err(solver::Error::WrongLine(diagnostic)) => assert_eq!(diagnostic.line_number, 5),
+            outcome => panic!("{:?}", outcome.map(|_| ())),
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use std::{collections::HashMap, io::BufRead, str::FromStr};
+
+use advent_derive::FromLine;
+
+use crate::solver::{Example, Parse, Solver};
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, FromLine)]
+#[pattern("{x},{y}")]
+pub struct Point {
+    x: i64,
+    y: i64,
+}
+
+impl From<(i64, i64)> for Point {
+    fn from(p: (i64, i64)) -> Self {
+        Point { x: p.0, y: p.1 }
+    }
+}
+
+#[derive(Debug, Clone, FromLine)]
+#[pattern("{start} -> {end}")]
+pub struct Line {
+    start: Point,
+    end: Point,
+}
+
+impl Line {
+    pub fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+    pub fn is_simple(&self) -> bool {
+        self.is_horizontal() || self.is_vertical()
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.start.x - self.end.x == self.start.y - self.end.y
+            || self.start.x - self.end.x == -(self.start.y - self.end.y)
+    }
+}
+
+impl From<(usize, usize, usize, usize)> for Line {
+    fn from(line: (usize, usize, usize, usize)) -> Self {
+        Line {
+            start: Point {
+                x: line.0 as i64,
+                y: line.1 as i64,
+            },
+            end: Point {
+                x: line.2 as i64,
+                y: line.3 as i64,
+            },
+        }
+    }
+}
+
+impl IntoIterator for Line {
+    type Item = Point;
+
+    type IntoIter = LineIterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        LineIterator {
+            line: self,
+            started: false,
+            finished: false,
+        }
+    }
+}
+
+pub struct LineIterator {
+    line: Line,
+    started: bool,
+    finished: bool,
+}
+
+fn orientation(start: i64, end: i64) -> i64 {
+    match end - start {
+        delta if delta < 0 => -1i64,
+        delta if delta > 0 => 1,
+        _ => 0,
+    }
+}
+
+impl Iterator for LineIterator {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some(self.line.start);
+        }
+        if self.finished {
+            return None;
+        }
+        if self.line.is_simple() || self.line.is_diagonal() {
+            if self.line.end == self.line.start {
+                self.finished = true;
+                None
+            } else {
+                let delta_x = orientation(self.line.start.x, self.line.end.x);
+                let delta_y = orientation(self.line.start.y, self.line.end.y);
+                self.line.start.x += delta_x;
+                self.line.start.y += delta_y;
+                Some(self.line.start)
+            }
+        } else {
+            unimplemented!()
+        }
+    }
+}
+
+const EXAMPLE: &str = "\
+0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+/// Lines of vents over each point of the ocean floor, counted as the lines are read so that
+/// memory depends on the area of the floor rather than on the length of the input.
+#[derive(Debug, Default)]
+pub struct Floor {
+    /// Horizontal and vertical lines, the only ones of part 1
+    straight: HashMap<Point, usize>,
+    /// Diagonal lines along with the straight ones
+    all: HashMap<Point, usize>,
+}
+
+impl Floor {
+    fn cover(&mut self, line: Line) {
+        let straight = line.is_simple();
+        if !straight && !line.is_diagonal() {
+            return;
+        }
+        for point in line {
+            if straight {
+                *self.straight.entry(point).or_default() += 1;
+            }
+            *self.all.entry(point).or_default() += 1;
+        }
+    }
+}
+
+fn overlaps(counts: &HashMap<Point, usize>) -> usize {
+    counts.values().filter(|count| **count >= 2).count()
+}
+
+#[derive(Debug, Default)]
+pub struct First {}
+impl Parse for First {
+    type ProblemModel = Floor;
+
+    fn parse(&self, input: &mut dyn BufRead) -> Result<Self::ProblemModel, crate::solver::Error> {
+        let mut floor = Floor::default();
+        for (n, line) in input.lines().enumerate() {
+            let line = line?;
+            let vents =
+                Line::from_str(&line).map_err(|e| crate::solver::Error::WrongLine(e.into_diagnostic(n, &line)))?;
+            floor.cover(vents);
+        }
+        Ok(floor)
+    }
+}
+
+impl Solver for First {
+    type ProblemModel = Floor;
+
+    type Solution = usize;
+
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, crate::solver::Error> {
+        Ok(overlaps(&model.straight))
+    }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 5,
+        }]
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Second {}
+
+impl Solver for Second {
+    type ProblemModel = Floor;
+
+    type Solution = usize;
+
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, crate::solver::Error> {
+        Ok(overlaps(&model.all))
+    }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 12,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    #[test]
+    fn it_iterates_diagonals() {
+        let line: Line = (1, 1, 4, 4).into();
+        let expected: Vec<Point> = [(1, 1), (2, 2), (3, 3), (4, 4)].map(Into::into).into();
+        let result = line.into_iter().collect::<Vec<_>>();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn it_knows_which_lines_are_diagonals() {
+        let diagonals: Vec<Line> = [(5, 5, 0, 0), (5, 5, 10, 0), (5, 5, 10, 10), (5, 5, 0, 10)]
+            .map(Into::into)
+            .into();
+        for line in diagonals {
+            assert!(line.is_diagonal(), "{:?} supposed to be diagonal", line)
+        }
+    }
+
+    #[test]
+    fn it_parses_lines_from_their_pattern() {
+        let line = Line::from_str("0,9 -> 5,9").unwrap();
+        assert_eq!((line.start, line.end), (Point { x: 0, y: 9 }, Point { x: 5, y: 9 }));
+
+        let error = Line::from_str("0,9 -> 5,x").unwrap_err();
+        assert_eq!((error.column, error.width), (9, 1));
+        let error = Line::from_str("0,9 -> 5,9 ").unwrap_err();
+        assert_eq!(
+            (error.column, error.error.as_str()),
+            (9, "Not a number: invalid digit found in string")
+        );
+    }
+}
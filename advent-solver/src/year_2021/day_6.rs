@@ -0,0 +1,170 @@
+use crate::combinator::{diagnose, literal, map_res, number, separated};
+use crate::param::{Param, Params};
+use crate::solver::{Error, Example, Parse, Solver};
+use num_bigint::BigInt;
+use std::io::BufRead;
+
+const EXAMPLE: &str = "3,4,3,1,2";
+
+/// Fishes counted by timer, the crew growing exponentially past what any integer holds.
+#[derive(Default, Debug, Clone)]
+pub struct LanterfishCrew {
+    short_cycles: [BigInt; 7],
+    long_cycles: [BigInt; 9],
+}
+
+impl LanterfishCrew {
+    fn size(&self) -> BigInt {
+        self.short_cycles.iter().chain(self.long_cycles.iter()).sum()
+    }
+
+    /// Moves the crew on by a day.
+    fn grow(&mut self) {
+        let (adults, youngs) = (self.short_cycles[0].clone(), self.long_cycles[0].clone());
+        //Cycling through old age, youngs become adults
+        self.short_cycles.rotate_left(1);
+        self.short_cycles[6] += &youngs;
+
+        //Youngs become older, with baby fishes
+        self.long_cycles.rotate_left(1);
+        self.long_cycles[8] = adults + youngs;
+    }
+
+    fn size_after(mut self, days: usize) -> BigInt {
+        for _ in 0..days {
+            self.grow();
+        }
+        self.size()
+    }
+}
+
+impl Parse for LanterfishCrew {
+    type ProblemModel = LanterfishCrew;
+
+    fn parse(&self, input: &mut dyn BufRead) -> Result<Self::ProblemModel, crate::solver::Error> {
+        let line = input.lines().next().transpose()?.ok_or(Error::EmptyFile)?;
+        let timer = map_res(number(), |timer: usize| {
+            (timer < 16)
+                .then_some(timer)
+                .ok_or(format!("Timer {} is out of range", timer))
+        });
+        let crew_cyle = diagnose(0, &line, separated(timer, literal(",")))
+            .map_err(Error::WrongLine)?
+            .into_iter()
+            .fold([0; 16], |mut acc, v| {
+                acc[v] += 1;
+                acc
+            });
+        log::debug!("Crew by timer: {:?}", crew_cyle);
+        let short_cycles: [usize; 7] = crew_cyle[0..7].try_into().unwrap();
+        let long_cycles: [usize; 9] = crew_cyle[7..(7 + 9)].try_into().unwrap();
+        if long_cycles.iter().any(|c| *c > 0) {
+            return Err(crate::solver::Error::ExpectationUnfulfilled(
+                "Found unexpected young fish in first generation".into(),
+            ));
+        }
+        Ok(LanterfishCrew {
+            short_cycles: short_cycles.map(BigInt::from),
+            long_cycles: long_cycles.map(BigInt::from),
+        })
+    }
+}
+
+/// Days the crew grows for, which is all that tells the parts apart.
+const fn days(default: usize) -> Param {
+    Param {
+        name: "days",
+        help: "Days the crew grows for",
+        default,
+        min: 0,
+        max: None,
+        example: None,
+    }
+}
+
+const DAYS_ONE: Param = days(80);
+const DAYS_TWO: Param = days(256);
+
+pub struct First {
+    days: usize,
+}
+
+impl Default for First {
+    fn default() -> Self {
+        Self { days: DAYS_ONE.default }
+    }
+}
+
+impl Solver for First {
+    type ProblemModel = LanterfishCrew;
+
+    type Solution = BigInt;
+
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, Error> {
+        Ok(model.clone().size_after(self.days))
+    }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 5934.into(),
+        }]
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![DAYS_ONE]
+    }
+
+    fn set_params(&mut self, params: &Params) {
+        self.days = params.get(&DAYS_ONE);
+    }
+}
+
+pub struct Second {
+    days: usize,
+}
+
+impl Default for Second {
+    fn default() -> Self {
+        Self { days: DAYS_TWO.default }
+    }
+}
+
+impl Solver for Second {
+    type ProblemModel = LanterfishCrew;
+
+    type Solution = BigInt;
+
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, Error> {
+        Ok(model.clone().size_after(self.days))
+    }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 26984457539u64.into(),
+        }]
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![DAYS_TWO]
+    }
+
+    fn set_params(&mut self, params: &Params) {
+        self.days = params.get(&DAYS_TWO);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_grows_crews_for_any_number_of_days() {
+        let crew = LanterfishCrew::default().parse(&mut EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(First { days: 18 }.solve(&crew).unwrap(), 26.into());
+        assert_eq!(Second { days: 0 }.solve(&crew).unwrap(), 5.into());
+        let huge: BigInt = "379589061144698259131825683795505058481".parse().unwrap();
+        assert_eq!(Second { days: 1000 }.solve(&crew).unwrap(), huge);
+    }
+}
@@ -1,8 +1,11 @@
-use crate::solver::{Error, Parse, Solver};
-use std::{num::ParseIntError, ops::Deref, str::FromStr};
+use crate::combinator::{diagnose, literal, number, separated};
+use crate::solver::{Error, Example, Parse, Solver, SolvingError};
+use std::io::BufRead;
 
 use itertools::Itertools;
 
+const EXAMPLE: &str = "16,1,2,0,4,2,7,1,2,14";
+
 #[derive(Default)]
 pub struct CrabCrew(Vec<usize>);
 
@@ -11,20 +14,9 @@ impl CrabCrew {}
 impl Parse for CrabCrew {
     type ProblemModel = CrabCrew;
 
-    fn parse(&self, lines: &[String]) -> Result<Self::ProblemModel, crate::solver::Error> {
-        let positions = lines
-            .first()
-            .ok_or(Error::EmptyFile)?
-            .split(",")
-            .into_iter()
-            .map(AsRef::as_ref)
-            .map(usize::from_str)
-            .collect::<Result<Vec<usize>, ParseIntError>>()
-            .map_err(|_| Error::WrongLine {
-                description: "Wrong interger".into(),
-                line_number: 0,
-                line: lines[0].clone(),
-            })?;
+    fn parse(&self, input: &mut dyn BufRead) -> Result<Self::ProblemModel, crate::solver::Error> {
+        let line = input.lines().next().transpose()?.ok_or(Error::EmptyFile)?;
+        let positions: Vec<usize> = diagnose(0, &line, separated(number(), literal(","))).map_err(Error::WrongLine)?;
         let max = positions.iter().max().ok_or(Error::Unexpected)?;
 
         let mut crabs_count_by_pos = vec![0; max + 1];
@@ -43,7 +35,7 @@ fn accumulated(vec: &[usize]) -> Vec<usize> {
     //    acc
     //})
 
-    let mut accumulated: Vec<usize> = vec.iter().cloned().collect();
+    let mut accumulated: Vec<usize> = vec.to_vec();
     let mut acc = 0;
     for v in accumulated.iter_mut() {
         *v += acc;
@@ -81,20 +73,24 @@ impl Solver for First {
 
     type Solution = usize;
 
-    fn solve(&self, model: Self::ProblemModel) -> Result<Self::Solution, Error> {
-        let accumulated = accumulated(&model.0);
-
-        let total = accumulated.last().unwrap();
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, Error> {
         let median = median(&model.0).unwrap();
         let score = model
             .0
             .iter()
-            .zip(manhantan_dist_vec(model.0.len(), median.0).into_iter())
+            .zip(manhantan_dist_vec(model.0.len(), median.0))
             .map(|(count, dist)| count * dist)
             .sum();
 
         Ok(score)
     }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 37,
+        }]
+    }
 }
 
 #[derive(Default)]
@@ -105,7 +101,7 @@ impl Solver for Second {
 
     type Solution = usize;
 
-    fn solve(&self, model: Self::ProblemModel) -> Result<Self::Solution, Error> {
+    fn solve(&self, model: &Self::ProblemModel) -> Result<Self::Solution, Error> {
         let count: usize = model.0.len();
 
         let costs: Vec<usize> = (0..count)
@@ -122,7 +118,14 @@ impl Solver for Second {
 
         let min_cost = costs.into_iter().min();
 
-        min_cost.ok_or(Error::Unexpected)
+        min_cost.ok_or(SolvingError::NoSolution("no crab to align".into()).into())
+    }
+
+    fn examples(&self) -> Vec<Example<Self::Solution>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 168,
+        }]
     }
 }
 
@@ -24,9 +24,9 @@ impl From<HashSet<char>> for Digit {
     }
 }
 
-impl Into<usize> for Digit {
-    fn into(self) -> usize {
-        digits().iter().position(|d| *d == self).unwrap()
+impl From<Digit> for usize {
+    fn from(digit: Digit) -> Self {
+        digits().iter().position(|d| *d == digit).unwrap()
     }
 }
 
@@ -61,7 +61,7 @@ impl From<String> for Digit {
 impl From<&str> for Digit {
     fn from(s: &str) -> Self {
         let chars = ['a', 'b', 'c', 'd', 'e', 'f', 'g'];
-        Self(BinSeq(chars.map(|c| s.chars().into_iter().contains(&c))))
+        Self(BinSeq(chars.map(|c| s.chars().contains(&c))))
     }
 }
 
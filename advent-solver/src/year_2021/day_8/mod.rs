@@ -2,29 +2,17 @@ mod digit;
 use digit::Digit;
 
 use std::collections::{HashMap, HashSet};
-use std::convert::Infallible;
-
-use std::str::FromStr;
+use std::io::BufRead;
 
 use derive_more::Display;
-use derive_more::From;
 use itertools::Itertools;
 
-use std::fmt::Display;
-
 use crate::application::{Application, ApplicationExt};
 
 use crate::application::Element;
-use crate::bin_seq::BinSeq;
-use crate::pop_array::PopArray;
-use crate::solver::{ParsingError, Problem};
-
-#[derive(Display, Debug)]
-enum NoteParsingError {
-    MissingSignals,
-    NotAMiddlePipe(String),
-    MissingDigits,
-}
+use crate::combinator::{array, diagnose, literal, map, pair, preceded, word, ParseResult};
+use crate::answer::Answer;
+use crate::solver::{Example, ParsingError, Problem, SolvingError};
 
 // #[derive(From)]
 // struct Ens<T>(HashSet<T>);
@@ -41,22 +29,7 @@ enum NoteParsingError {
 //     }
 // }
 
-struct Len {}
-
 impl Element for usize {}
-impl Application for Len {
-    type In = Digit;
-
-    type Out = usize;
-
-    fn start(&self) -> HashSet<Self::In> {
-        digit::digits().into_iter().collect()
-    }
-
-    fn image(&self, e: &Self::In) -> Self::Out {
-        e.lighted_on_segments()
-    }
-}
 
 struct Discriminant {
     start: HashSet<Digit>,
@@ -77,27 +50,6 @@ impl Application for Discriminant {
     }
 }
 
-struct Identifier {
-    identified: Digit,
-    disc: Discriminant,
-    expected_value: usize,
-}
-
-impl Element for bool {}
-impl Application for Identifier {
-    type In = Digit;
-
-    type Out = bool;
-
-    fn start(&self) -> HashSet<Self::In> {
-        self.disc.start()
-    }
-
-    fn image(&self, e: &Self::In) -> Self::Out {
-        self.disc.image(e) == self.expected_value
-    }
-}
-
 #[derive(Display, Debug)]
 #[display(fmt = "signals: {:?}, digits: {:?}", signals, digits)]
 struct Note {
@@ -105,37 +57,21 @@ struct Note {
     digits: [Digit; 4],
 }
 
-impl FromStr for Note {
-    type Err = NoteParsingError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut tokens = s.split(" ").into_iter();
-
-        let signals = tokens
-            .pop_array::<10>()
-            .map_err(|_| NoteParsingError::MissingSignals)?
-            .map(Into::into);
-
-        match tokens.next() {
-            Some("|") => (),
-            Some(wrong) => return Err(NoteParsingError::NotAMiddlePipe(wrong.to_string())),
-            _ => return Err(NoteParsingError::NotAMiddlePipe("".into())),
-        };
-
-        let digits = tokens
-            .pop_array::<4>()
-            .or(Err(NoteParsingError::MissingDigits))?
-            .map(Into::into);
-
-        Ok(Note { signals, digits })
+impl Note {
+    fn parser() -> impl Fn(&str) -> ParseResult<'_, Note> {
+        let digit = || map(word(), Digit::from);
+        let signals = array(digit(), literal(" "));
+        let digits = array(digit(), literal(" "));
+        map(pair(signals, preceded(literal(" | "), digits)), |(signals, digits)| Note {
+            signals,
+            digits,
+        })
     }
-}
 
-impl Note {
     fn decode(&self, mapping: &HashMap<Digit, Digit>) -> usize {
         let mut number = 0;
         for secret in self.digits {
-            let ori = mapping.iter().find(|(k, v)| **v == secret).unwrap().0;
+            let ori = mapping.iter().find(|(_, v)| **v == secret).unwrap().0;
             let ori: usize = (*ori).into();
             number *= 10;
             number += ori;
@@ -144,6 +80,18 @@ impl Note {
     }
 }
 
+const EXAMPLE: &str = "\
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+
 pub struct SevenSegmentSearch {
     notes: Vec<Note>,
 }
@@ -184,22 +132,19 @@ fn build_plan() -> Vec<(Digit, Digit, usize)> {
 }
 
 impl Problem for SevenSegmentSearch {
-    fn parse(lines: &[String]) -> Result<Self, crate::solver::ParsingError> {
-        let notes = lines
-            .into_iter()
+    fn parse(input: &mut dyn BufRead) -> Result<Self, crate::solver::ParsingError> {
+        let notes = input
+            .lines()
             .enumerate()
             .map(|(n, line)| {
-                Note::from_str(&line).map_err(|e| ParsingError::IncorrectLine {
-                    number: n,
-                    line: line.clone(),
-                    description: e.to_string(),
-                })
+                let line = line?;
+                diagnose(n, &line, Note::parser()).map_err(ParsingError::IncorrectLine)
             })
             .collect::<Result<Vec<_>, _>>()?;
         Ok(SevenSegmentSearch { notes })
     }
 
-    fn part_one(&self) -> Result<usize, crate::solver::SolvingError> {
+    fn part_one(&self) -> Result<Answer, SolvingError> {
         let easy_numbers = |n: &usize| [2, 3, 4, 7].contains(n);
         let easy_count = self
             .notes
@@ -208,10 +153,10 @@ impl Problem for SevenSegmentSearch {
             .map(|digit| digit.lighted_on_segments())
             .filter(easy_numbers)
             .count();
-        Ok(easy_count)
+        Ok(easy_count.into())
     }
 
-    fn part_two(&self) -> Result<usize, crate::solver::SolvingError> {
+    fn part_two(&self) -> Result<Answer, SolvingError> {
         let plan = build_plan();
         let mut the_sum = 0;
         for note in &self.notes {
@@ -223,17 +168,33 @@ impl Problem for SevenSegmentSearch {
             for (to_found, disc, value) in &plan {
                 let discriminant = Discriminant {
                     start: unknown.clone(),
-                    digit: mapping.get(&disc).unwrap().clone(),
+                    digit: *mapping.get(disc).unwrap(),
                 };
-                let ante: [Digit; 1] = discriminant.antecedent(&value).try_into().unwrap();
+                let ante: [Digit; 1] = discriminant.antecedent(value).try_into().map_err(|_| {
+                    SolvingError::Ambiguous(format!("digit {} can't be singled out in {}", to_found, note))
+                })?;
 
                 unknown.remove(&ante[0]);
-                mapping.insert(*to_found, ante[0].clone());
+                mapping.insert(*to_found, ante[0]);
             }
 
             the_sum += note.decode(&mapping);
         }
-        Ok(the_sum)
+        Ok(the_sum.into())
+    }
+
+    fn examples_part_one() -> Vec<Example<Answer>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 26.into(),
+        }]
+    }
+
+    fn examples_part_two() -> Vec<Example<Answer>> {
+        vec![Example {
+            input: EXAMPLE,
+            answer: 61229.into(),
+        }]
     }
 }
 
@@ -242,13 +203,11 @@ mod test {
 
     use std::collections::HashMap;
 
-    use itertools::Itertools;
-
     use super::*;
     #[test]
     fn it_parses_note() {
         let note = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
-        Note::from_str(note).unwrap();
+        diagnose(0, note, Note::parser()).unwrap();
     }
 
     #[test]
@@ -260,7 +219,7 @@ mod test {
         }
 
         let note = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
-        let note = Note::from_str(note).unwrap();
+        let note = diagnose(0, note, Note::parser()).unwrap();
 
         let mut mapping = HashMap::new();
         mapping.insert(Digit::off(), Digit::off());
@@ -268,12 +227,12 @@ mod test {
         for (to_found, disc, value) in plan {
             let discriminant = Discriminant {
                 start: unknown.clone(),
-                digit: mapping.get(&disc).unwrap().clone(),
+                digit: *mapping.get(&disc).unwrap(),
             };
             let ante: [Digit; 1] = discriminant.antecedent(&value).try_into().unwrap();
             println!("Found {} to be {:?}", to_found, ante);
             unknown.remove(&ante[0]);
-            mapping.insert(to_found, ante[0].clone());
+            mapping.insert(to_found, ante[0]);
         }
 
         let mut expected: HashMap<_, _> = [
@@ -288,12 +247,12 @@ mod test {
             (0, "cagedb"),
             (1, "ab"),
         ]
-        .map(|(d, encoding)| (digit::digits()[d].clone(), Digit::from(encoding)))
+        .map(|(d, encoding)| (digit::digits()[d], Digit::from(encoding)))
         .into();
         expected.insert(Digit::off(), Digit::off());
 
         for secret in note.digits {
-            let ori = mapping.iter().find(|(k, v)| v == &&secret).unwrap().0;
+            let ori = mapping.iter().find(|(_, v)| v == &&secret).unwrap().0;
             println!("{}", ori);
         }
     }
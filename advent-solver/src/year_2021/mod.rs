@@ -0,0 +1,147 @@
+//! Puzzles of Advent of Code 2021.
+use crate::registry::{Part, Registry};
+use crate::schooler::Schooler;
+use crate::solver::{PartOne, PartTwo, ProblemParser};
+
+pub mod day_1;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_17;
+
+use day_17::ProbeSystem;
+use day_8::SevenSegmentSearch;
+
+/// The one place where a new day of the year gets registered.
+pub fn calendar() -> Registry {
+    let mut registry = Registry::default();
+    registry
+        .register(1, Part::One, Schooler::<day_1::Parser, day_1::First>::new())
+        .register(1, Part::Two, Schooler::<day_1::Parser, day_1::Second>::new())
+        .register(2, Part::One, Schooler::<day_2::Parser, day_2::First>::new())
+        .register(2, Part::Two, Schooler::<day_2::Parser, day_2::Second>::new())
+        .register(3, Part::One, Schooler::<day_3::Parser, day_3::First>::new())
+        .register(3, Part::Two, Schooler::<day_3::Parser, day_3::Second>::new())
+        .register(4, Part::One, Schooler::<day_4::Parser, day_4::First>::new())
+        .register(4, Part::Two, Schooler::<day_4::Parser, day_4::Second>::new())
+        .register(5, Part::One, Schooler::<day_5::First, day_5::First>::new())
+        .register(5, Part::Two, Schooler::<day_5::First, day_5::Second>::new())
+        .register(6, Part::One, Schooler::<day_6::LanterfishCrew, day_6::First>::new())
+        .register(6, Part::Two, Schooler::<day_6::LanterfishCrew, day_6::Second>::new())
+        .register(7, Part::One, Schooler::<day_7::CrabCrew, day_7::First>::new())
+        .register(7, Part::Two, Schooler::<day_7::CrabCrew, day_7::Second>::new())
+        .register(
+            8,
+            Part::One,
+            Schooler::<ProblemParser<SevenSegmentSearch>, PartOne<_>>::new(),
+        )
+        .register(
+            8,
+            Part::Two,
+            Schooler::<ProblemParser<SevenSegmentSearch>, PartTwo<_>>::new(),
+        )
+        .register(17, Part::One, Schooler::<ProblemParser<ProbeSystem>, PartOne<_>>::new())
+        .register(17, Part::Two, Schooler::<ProblemParser<ProbeSystem>, PartTwo<_>>::new());
+    registry
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_registers_both_parts_of_every_day() {
+        let registry = calendar();
+        for day in [1, 2, 3, 4, 5, 6, 7, 8, 17] {
+            assert!(registry.get(day, Part::One).is_some(), "day {} part 1 missing", day);
+            assert!(registry.get(day, Part::Two).is_some(), "day {} part 2 missing", day);
+        }
+        assert!(registry.get(26, Part::One).is_none());
+    }
+
+    #[test]
+    fn it_solves_every_example() {
+        let mut failures = vec![];
+        for (day, part, exercice) in calendar().iter() {
+            for example in exercice.examples() {
+                let outcome = crate::runner::run(exercice.for_examples().as_ref(), &mut example.reader()).outcome;
+                match outcome {
+                    Ok(answer) if answer == example.answer => (),
+                    outcome => failures.push(format!(
+                        "day {} part {}: expected {}, got {:?}",
+                        day, part, example.answer, outcome
+                    )),
+                }
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn it_lends_one_model_to_both_parts() {
+        let registry = calendar();
+        for day in registry.days() {
+            let (parts, exercices): (Vec<_>, Vec<_>) = registry
+                .day(day)
+                .into_iter()
+                .map(|(part, exercice)| (part, exercice.for_examples()))
+                .unzip();
+            assert_eq!(parts, [Part::One, Part::Two]);
+            let exercices: Vec<_> = exercices.iter().map(AsRef::as_ref).collect();
+            let examples: Vec<_> = exercices.iter().flat_map(|exercice| exercice.examples()).collect();
+            match &examples[..] {
+                [one, two] if one.input == two.input => {
+                    let reports = crate::runner::run_parts(&exercices, &mut one.reader());
+                    let answers: Vec<_> = reports.into_iter().map(|report| report.outcome.unwrap()).collect();
+                    assert_eq!(answers, [one.answer.clone(), two.answer.clone()], "day {}", day);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    #[test]
+    fn it_copes_with_huge_values_of_unbounded_params() {
+        use crate::solver::{Error, Exercice};
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        // Growing a crew of lanternfishes for ever can only run out of time
+        let budget = Some(Duration::from_secs(1));
+        for (day, part, exercice) in calendar().iter() {
+            for param in exercice.params().into_iter().filter(|param| param.max.is_none()) {
+                let assignment = format!("{}={}", param.name, usize::MAX).parse().unwrap();
+                let params = crate::param::Params::new(&[assignment], &exercice.params()).unwrap();
+                let exercice: Arc<dyn Exercice> = exercice.with_params(&params.for_examples(&exercice.params())).into();
+                for example in exercice.examples() {
+                    let report = crate::runner::run_isolated(exercice.clone(), Box::new(example.reader()), budget);
+                    assert!(
+                        !matches!(report.outcome, Err(Error::Panicked(_) | Error::Unexpected)),
+                        "day {} part {} with {}={}: {:?}",
+                        day,
+                        part,
+                        param.name,
+                        usize::MAX,
+                        report.outcome
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn it_hands_params_to_copies_of_exercices() {
+        let registry = calendar();
+        let exercice = registry.get(6, Part::One).unwrap();
+        let params = crate::param::Params::new(&["days=18".parse().unwrap()], &exercice.params()).unwrap();
+        let grown = exercice.with_params(&params);
+        let report = crate::runner::run(grown.as_ref(), &mut "3,4,3,1,2".as_bytes());
+        assert_eq!(report.outcome.unwrap(), 26.into());
+        let report = crate::runner::run(exercice.as_ref(), &mut "3,4,3,1,2".as_bytes());
+        assert_eq!(report.outcome.unwrap(), 5934.into());
+    }
+}
@@ -0,0 +1,46 @@
+//! The library function behind the stand-alone day binaries answers the way the `advent-solver`
+//! command line does, each day crate checking its binary against the puzzle examples.
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use advent_solver::registry::{calendar, Part};
+
+fn command_line(day: usize, params: &[String], input: &str) -> String {
+    let params = params.iter().flat_map(|param| ["--param", param]);
+    let mut child = Command::new(env!("CARGO_BIN_EXE_advent-solver"))
+        .args(["--year", "2021", &day.to_string(), "--input", "-"])
+        .args(params)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success(), "day {} failed on the command line", day);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn standalone_days_answer_like_the_command_line() {
+    let registry = calendar(2021).unwrap();
+    for day in [1, 2, 3] {
+        let exercice = registry.get(day, Part::One).unwrap();
+        let input = exercice.examples()[0].input;
+        // Examples may call for other values of the puzzle constants than the inputs
+        let params: Vec<_> = exercice
+            .params()
+            .into_iter()
+            .filter_map(|param| param.example.map(|value| format!("{}={}", param.name, value)))
+            .collect();
+        let mut output = vec![];
+        assert!(advent_solver::standalone(2021, day, &params, &mut input.as_bytes(), &mut output).unwrap());
+        let output = String::from_utf8(output).unwrap();
+        assert!(
+            output.starts_with("Part 1: ") && output.contains("\nPart 2: "),
+            "day {}: {}",
+            day,
+            output
+        );
+        assert_eq!(output, command_line(day, &params, input), "day {}", day);
+    }
+}
@@ -0,0 +1,55 @@
+//! Checks shared by the tests of the stand-alone day binaries, each run on its puzzle examples.
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+use advent_solver::registry::calendar;
+
+/// Runs `binary` with `params`, feeding it `input` on stdin.
+fn run(binary: &str, params: &[String], input: &str) -> Output {
+    let mut child = Command::new(binary)
+        .args(params)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+/// Checks that `binary` prints the recorded answer of every example of the day, for each part.
+pub fn answers_the_examples(binary: &str, year: u16, day: usize) {
+    let registry = calendar(year).unwrap();
+    let exercices = registry.day(day);
+    assert!(!exercices.is_empty(), "no solver for day {} of {}", day, year);
+    // Examples may call for other values of the puzzle constants than the inputs
+    let params: Vec<_> = exercices
+        .iter()
+        .flat_map(|(_, exercice)| exercice.params())
+        .filter_map(|param| param.example.map(|value| format!("{}={}", param.name, value)))
+        .collect();
+    for (part, exercice) in exercices {
+        for example in exercice.examples() {
+            let output = run(binary, &params, example.input);
+            assert!(output.status.success(), "day {} failed on its example", day);
+            let expected = format!("Part {}: {}", part, example.answer);
+            let stdout = String::from_utf8(output.stdout).unwrap();
+            assert!(
+                stdout.lines().any(|line| line == expected),
+                "expected {}, got {}",
+                expected,
+                stdout
+            );
+        }
+    }
+}
+
+/// Checks that `binary` exits with status 1, answering nothing, on unreadable input or unknown params.
+pub fn fails_on_wrong_input(binary: &str) {
+    for (params, input) in [(vec![], "?\n"), (vec!["nothing=0".to_string()], "")] {
+        let output = run(binary, &params, input);
+        assert_eq!(output.status.code(), Some(1), "{:?} on {:?}", params, input);
+        assert!(output.stdout.is_empty());
+        assert!(!output.stderr.is_empty());
+    }
+}
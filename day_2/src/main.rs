@@ -1,19 +1,11 @@
-#![feature(array_zip)]
-
-use std::io::{self, BufRead, BufReader};
-
-mod day_2;
-use day_2::Move;
-
-use crate::day_2::Error;
-
-fn main() {
-    let stdin = BufReader::new(io::stdin());
-    let lines = stdin.lines().collect::<Result<Vec<_>, io::Error>>().unwrap();
-    let moves = lines
-        .into_iter()
-        .map(Move::try_from)
-        .collect::<Result<Vec<_>, Error>>()
-        .unwrap();
-    println!("{:?}", day_2::part_2(moves));
+//! Day 2 of 2021 on its own, reading the puzzle input on stdin and `<name>=<value>` changes of
+//! the puzzle constants as arguments.
+use std::io;
+
+fn main() -> io::Result<()> {
+    let params: Vec<String> = std::env::args().skip(1).collect();
+    if !advent_solver::standalone(2021, 2, &params, &mut io::stdin().lock(), &mut io::stdout())? {
+        std::process::exit(1);
+    }
+    Ok(())
 }
@@ -0,0 +1,12 @@
+//! The day 2 binary answers the puzzle examples, and fails on what it can't solve.
+const BINARY: &str = env!("CARGO_BIN_EXE_day_2");
+
+#[test]
+fn it_answers_the_examples() {
+    advent_testing::answers_the_examples(BINARY, 2021, 2);
+}
+
+#[test]
+fn it_fails_on_wrong_input() {
+    advent_testing::fails_on_wrong_input(BINARY);
+}
@@ -1,11 +1,11 @@
-#![feature(array_zip)]
+//! Day 3 of 2021 on its own, reading the puzzle input on stdin and `<name>=<value>` changes of
+//! the puzzle constants as arguments.
+use std::io;
 
-use std::io::{self, BufRead, BufReader};
-
-mod day_3;
-
-fn main() {
-    let stdin = BufReader::new(io::stdin());
-    let lines = stdin.lines().collect::<Result<Vec<_>, io::Error>>().unwrap();
-    println!("{:?}", day_3::first_part(lines));
+fn main() -> io::Result<()> {
+    let params: Vec<String> = std::env::args().skip(1).collect();
+    if !advent_solver::standalone(2021, 3, &params, &mut io::stdin().lock(), &mut io::stdout())? {
+        std::process::exit(1);
+    }
+    Ok(())
 }